@@ -0,0 +1,30 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fibo_calc::{FiboBuilder, FiboCalc, ImplementationFibo, LinealFibo};
+use num_bigint::BigInt;
+use std::hint::black_box;
+
+fn bench_lineal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lineal iteration");
+    for terms in [1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(terms), &terms, |b, &terms| {
+            b.iter(|| {
+                let fibo = LinealFibo::new(Some((BigInt::from(0), BigInt::from(1))));
+                black_box(fibo.take(terms).last())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_matmul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matmul calc_one");
+    for n in [10_000u64, 1_000_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| black_box(FiboCalc::new(FiboBuilder::default()).calc_one_number(n.into())))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_lineal, bench_matmul);
+criterion_main!(benches);
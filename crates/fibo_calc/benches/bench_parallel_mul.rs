@@ -0,0 +1,71 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fibo_calc::parallel_mul;
+use num_bigint::{BigInt, BigUint};
+use std::hint::black_box;
+
+/// F(n) has about 0.69·n bits, so these are the operands of n ≈ 1.4·10⁷ and 2.9·10⁷.
+const OPERAND_BITS: [u64; 2] = [10_000_000, 20_000_000];
+const THRESHOLDS: [u64; 5] = [1 << 18, 1 << 20, 1 << 22, 1 << 24, u64::MAX];
+
+/// Deterministic operand of exactly `bits` bits.
+fn operand(bits: u64, seed: u32) -> BigInt {
+    let mut state = seed;
+    let mut limbs: Vec<u32> = (0..bits / 32)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state
+        })
+        .collect();
+    if let Some(top) = limbs.last_mut() {
+        *top |= 1 << 31;
+    }
+    BigInt::from(BigUint::new(limbs))
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_mul mul");
+    group.sample_size(10);
+    for bits in OPERAND_BITS {
+        let (a, b) = (operand(bits, 1), operand(bits, 2));
+        group.bench_with_input(BenchmarkId::new("plain", bits), &bits, |bench, _| {
+            bench.iter(|| black_box(&a * &b))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", bits), &bits, |bench, _| {
+            bench.iter(|| black_box(parallel_mul::mul(&a, &b)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_square(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_mul square");
+    group.sample_size(10);
+    for bits in OPERAND_BITS {
+        let a = operand(bits, 3);
+        group.bench_with_input(BenchmarkId::new("plain", bits), &bits, |bench, _| {
+            bench.iter(|| black_box(&a * &a))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", bits), &bits, |bench, _| {
+            bench.iter(|| black_box(parallel_mul::square(&a)))
+        });
+    }
+    group.finish();
+}
+
+/// Where splitting starts to pay off on this machine; `u64::MAX` never splits.
+fn bench_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_mul square threshold");
+    group.sample_size(10);
+    let a = operand(OPERAND_BITS[0], 4);
+    for threshold in THRESHOLDS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threshold),
+            &threshold,
+            |bench, &threshold| bench.iter(|| black_box(parallel_mul::square_above(&a, threshold))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_mul, bench_square, bench_threshold);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+use fibo_calc::{FiboBuilder, FiboCalc, ImplementationFibo, LinealFibo};
+use num_bigint::BigInt;
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+
+/// Allocations made by `run`, printed next to the bytes they requested.
+fn report(name: &str, run: impl FnOnce()) {
+    let before = dhat::HeapStats::get();
+    run();
+    let after = dhat::HeapStats::get();
+
+    println!(
+        "{:<28} {:>8} allocations {:>12} bytes",
+        name,
+        after.total_blocks - before.total_blocks,
+        after.total_bytes - before.total_bytes
+    );
+}
+
+fn main() {
+    let _profiler = dhat::Profiler::builder().testing().build();
+
+    report("lineal 10000 terms", || {
+        let fibo = LinealFibo::new(Some((BigInt::from(0), BigInt::from(1))));
+        black_box(fibo.take(10_000).last());
+    });
+
+    report("matmul calc_one(1000000)", || {
+        black_box(FiboCalc::new(FiboBuilder::default()).calc_one_number(1_000_000.into()));
+    });
+}
@@ -39,6 +39,12 @@ async fn main() {
                 }
                 break;
             }
+            FiboTaskResult::Aggregate(_)
+            | FiboTaskResult::Search(_)
+            | FiboTaskResult::Verification(_)
+            | FiboTaskResult::Written(_)
+            | FiboTaskResult::Polynomials(_)
+            | FiboTaskResult::CheckpointFailed(_) => {}
         }
     }
 
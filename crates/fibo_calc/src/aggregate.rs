@@ -0,0 +1,231 @@
+use crate::implementation::matmul::nth_term;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateMode {
+    pub product_modulus: BigInt,
+    /// Also send the terms as `FiboTaskResult::Result` after the aggregate
+    pub keep_values: bool,
+}
+
+impl Default for AggregateMode {
+    fn default() -> Self {
+        Self {
+            product_modulus: BigInt::from(1_000_000_007),
+            keep_values: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiboAggregate {
+    pub count: usize,
+    pub sum: BigInt,
+    pub sum_of_squares: BigInt,
+    pub min: Option<BigInt>,
+    pub max: Option<BigInt>,
+    /// Product of all terms modulo `product_modulus` (always non-negative)
+    pub product_mod: BigInt,
+    pub product_modulus: BigInt,
+}
+
+impl FiboAggregate {
+    pub fn new(product_modulus: BigInt) -> Self {
+        Self {
+            count: 0,
+            sum: BigInt::zero(),
+            sum_of_squares: BigInt::zero(),
+            min: None,
+            max: None,
+            product_mod: modulo(&BigInt::one(), &product_modulus),
+            product_modulus,
+        }
+    }
+
+    pub fn from_values<'a>(
+        values: impl IntoIterator<Item = &'a BigInt>, product_modulus: BigInt,
+    ) -> Self {
+        let mut aggregate = Self::new(product_modulus);
+        for value in values {
+            aggregate.push(value);
+        }
+        aggregate
+    }
+
+    pub fn push(&mut self, num: &BigInt) {
+        self.count += 1;
+        self.sum += num;
+        self.sum_of_squares += num * num;
+        self.product_mod = modulo(&(&self.product_mod * num), &self.product_modulus);
+        self.update_extrema(num);
+    }
+
+    fn update_extrema(&mut self, num: &BigInt) {
+        if self.min.as_ref().is_none_or(|min| num < min) {
+            self.min = Some(num.clone());
+        }
+        if self.max.as_ref().is_none_or(|max| num > max) {
+            self.max = Some(num.clone());
+        }
+    }
+}
+
+/// Aggregates `start_nums` sequence over `range` without generating every
+/// term: Σ G(s..e) = G(e+1) − G(s+1) and Σ G(s..e)² = G(e−1)G(e) − G(s−1)G(s).
+pub(crate) fn closed_form(
+    start_nums: &(BigInt, BigInt), range: Range<usize>, mode: &AggregateMode,
+) -> FiboAggregate {
+    let mut aggregate = FiboAggregate::new(mode.product_modulus.clone());
+    if range.start >= range.end {
+        return aggregate;
+    }
+
+    let (s, e) = (range.start, range.end);
+    let before_start = if s == 0 {
+        &start_nums.1 - &start_nums.0
+    } else {
+        nth_term(start_nums, s - 1)
+    };
+
+    aggregate.count = e - s;
+    aggregate.sum = nth_term(start_nums, e + 1) - nth_term(start_nums, s + 1);
+    aggregate.sum_of_squares = nth_term(start_nums, e - 1) * nth_term(start_nums, e)
+        - before_start * nth_term(start_nums, s);
+    aggregate.product_mod = product_mod(start_nums, range.clone(), &mode.product_modulus);
+    closed_form_extrema(&mut aggregate, start_nums, range);
+
+    aggregate
+}
+
+/// Once two consecutive terms share a sign the sequence is monotone, so only
+/// the short sign-alternating prefix has to be scanned.
+fn closed_form_extrema(
+    aggregate: &mut FiboAggregate, start_nums: &(BigInt, BigInt), range: Range<usize>,
+) {
+    let mut index = range.start;
+    let mut current = nth_term(start_nums, index);
+    let mut next = nth_term(start_nums, index + 1);
+
+    while index < range.end {
+        aggregate.update_extrema(&current);
+
+        let same_sign = !(current.is_negative() && next.is_positive()
+            || current.is_positive() && next.is_negative());
+        if same_sign {
+            if index + 1 < range.end {
+                aggregate.update_extrema(&next);
+                aggregate.update_extrema(&nth_term(start_nums, range.end - 1));
+            }
+            return;
+        }
+
+        let following = &current + &next;
+        current = std::mem::replace(&mut next, following);
+        index += 1;
+    }
+}
+
+fn product_mod(start_nums: &(BigInt, BigInt), range: Range<usize>, modulus: &BigInt) -> BigInt {
+    let mut product = modulo(&BigInt::one(), modulus);
+    let mut current = modulo(&nth_term(start_nums, range.start), modulus);
+    let mut next = modulo(&nth_term(start_nums, range.start + 1), modulus);
+
+    for _ in range {
+        if product.is_zero() {
+            break;
+        }
+        product = modulo(&(&product * &current), modulus);
+        let following = modulo(&(&current + &next), modulus);
+        current = std::mem::replace(&mut next, following);
+    }
+
+    product
+}
+
+fn modulo(num: &BigInt, modulus: &BigInt) -> BigInt {
+    if modulus.is_zero() {
+        return num.clone();
+    }
+    let modulus = modulus.abs();
+    ((num % &modulus) + &modulus) % &modulus
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FiboBuilder;
+    use crate::calculator::ImplementationFibo;
+    use crate::implementation::lineal::LinealFibo;
+    use test_case::test_case;
+
+    fn brute_force(start: (i64, i64), range: Range<usize>, modulus: i64) -> FiboAggregate {
+        let start_nums = (BigInt::from(start.0), BigInt::from(start.1));
+        let values: Vec<BigInt> = [start_nums.0.clone(), start_nums.1.clone()]
+            .into_iter()
+            .chain(LinealFibo::new(Some(start_nums)))
+            .skip(range.start)
+            .take(range.len())
+            .collect();
+        FiboAggregate::from_values(&values, BigInt::from(modulus))
+    }
+
+    #[test_case((0, 1), 0..10, 1_000_000_007; "classic from zero")]
+    #[test_case((0, 1), 5..40, 1_000; "classic shifted")]
+    #[test_case((2, 1), 0..25, 97; "lucas")]
+    #[test_case((-5, 8), 0..20, 1_000; "negative start")]
+    #[test_case((13, -8), 0..20, 1_000; "sign alternating start")]
+    #[test_case((-3, -4), 3..18, 7; "negative sequence")]
+    #[test_case((0, 1), 7..8, 10; "single item")]
+    fn test_closed_form_matches_streaming(start: (i64, i64), range: Range<usize>, modulus: i64) {
+        let start_nums = (BigInt::from(start.0), BigInt::from(start.1));
+        let mode = AggregateMode {
+            product_modulus: BigInt::from(modulus),
+            ..Default::default()
+        };
+        assert_eq!(
+            closed_form(&start_nums, range.clone(), &mode),
+            brute_force(start, range, modulus)
+        );
+    }
+
+    #[test]
+    fn test_sum_identity() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        let aggregate = closed_form(&start_nums, 0..50, &AggregateMode::default());
+        assert_eq!(aggregate.sum, nth_term(&start_nums, 51) - 1);
+    }
+
+    #[test]
+    fn test_empty_range() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        let aggregate = closed_form(&start_nums, 4..4, &AggregateMode::default());
+        assert_eq!(
+            aggregate,
+            FiboAggregate::new(AggregateMode::default().product_modulus)
+        );
+        assert_eq!(aggregate.count, 0);
+        assert!(aggregate.min.is_none());
+    }
+
+    #[test]
+    fn test_push_accumulates() {
+        let values: Vec<BigInt> = [3, -2, 5].into_iter().map(BigInt::from).collect();
+        let aggregate = FiboAggregate::from_values(&values, BigInt::from(7));
+        assert_eq!(aggregate.count, 3);
+        assert_eq!(aggregate.sum, BigInt::from(6));
+        assert_eq!(aggregate.sum_of_squares, BigInt::from(38));
+        assert_eq!(aggregate.min, Some(BigInt::from(-2)));
+        assert_eq!(aggregate.max, Some(BigInt::from(5)));
+        assert_eq!(aggregate.product_mod, BigInt::from(5)); // -30 mod 7
+    }
+
+    #[test]
+    fn test_builder_aggregate_mode() {
+        let mut builder = FiboBuilder::default();
+        assert!(builder.get_aggregate().is_none());
+        builder.set_aggregate(Some(AggregateMode::default()));
+        assert_eq!(builder.get_aggregate(), Some(AggregateMode::default()));
+    }
+}
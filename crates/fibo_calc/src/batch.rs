@@ -0,0 +1,146 @@
+use crate::implementation::doubling::{fibo_pair, jump_pair};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use rayon::prelude::*;
+
+/// Gaps up to this size are walked with plain additions, larger ones jump.
+const LINEAR_GAP: u64 = 8;
+
+/// G(n) for every index in input order. Indices are 0-based like
+/// `set_range_by_id`; negative ones follow the reverse recurrence.
+pub(crate) fn calc_many(start_nums: &(BigInt, BigInt), indices: &[BigInt]) -> Vec<BigInt> {
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    if sorted.is_empty() {
+        return Vec::new();
+    }
+
+    let chunk_size = sorted.len().div_ceil(rayon::current_num_threads());
+    let values: Vec<BigInt> = sorted
+        .par_chunks(chunk_size)
+        .flat_map_iter(|chunk| {
+            let mut walker = TermWalker::new(start_nums.clone());
+            chunk.iter().map(move |index| walker.term(index))
+        })
+        .collect();
+
+    indices
+        .iter()
+        .map(|index| {
+            let position = sorted.binary_search(index).expect("index was sorted in");
+            values[position].clone()
+        })
+        .collect()
+}
+
+/// Terms at ascending indices, jumping from the classic pair (F(n), F(n+1))
+/// at each index to the next instead of recomputing every index from
+/// scratch. An index below the previous one starts over with fast doubling.
+pub(crate) struct TermWalker {
+    start_nums: (BigInt, BigInt),
+    current: Option<(BigInt, (BigInt, BigInt))>,
+    /// Pair of the last large gap, reused while a stepped selection repeats it
+    last_jump: Option<(BigInt, (BigInt, BigInt))>,
+}
+
+impl TermWalker {
+    pub(crate) fn new(start_nums: (BigInt, BigInt)) -> Self {
+        Self {
+            start_nums,
+            current: None,
+            last_jump: None,
+        }
+    }
+
+    pub(crate) fn term(&mut self, index: &BigInt) -> BigInt {
+        let pair = match self.current.take() {
+            Some((last, pair)) if &last <= index => {
+                let gap = index - last;
+                match gap.to_u64() {
+                    Some(small) if small <= LINEAR_GAP => (0..small).fold(pair, |(a, b), _| {
+                        let c = &a + &b;
+                        (b, c)
+                    }),
+                    _ => {
+                        let gap_pair = match self.last_jump.take() {
+                            Some((last_gap, gap_pair)) if last_gap == gap => gap_pair,
+                            _ => fibo_pair(&gap),
+                        };
+                        let next = jump_pair(&pair, &gap_pair);
+                        self.last_jump = Some((gap, gap_pair));
+                        next
+                    }
+                }
+            }
+            _ => fibo_pair(index),
+        };
+
+        let term = seeded_term(&self.start_nums, &pair);
+        self.current = Some((index.clone(), pair));
+        term
+    }
+}
+
+/// G(n) = a·F(n−1) + b·F(n) from the classic pair (F(n), F(n+1)).
+fn seeded_term((a, b): &(BigInt, BigInt), (f_n, f_next): &(BigInt, BigInt)) -> BigInt {
+    a * (f_next - f_n) + b * f_n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+
+    #[test]
+    fn test_input_order_and_duplicates() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        let indices: Vec<BigInt> = [90u64, 3, 1000, 3, 0, 91, 5000, 12]
+            .into_iter()
+            .map(BigInt::from)
+            .collect();
+
+        let expected: Vec<BigInt> = indices
+            .iter()
+            .map(|n| nth_term(&start_nums, n.to_usize().unwrap()))
+            .collect();
+        assert_eq!(calc_many(&start_nums, &indices), expected);
+    }
+
+    #[test]
+    fn test_custom_seeds_many_indices() {
+        let start_nums = (BigInt::from(-4), BigInt::from(7));
+        let indices: Vec<BigInt> = (0..2_000u64)
+            .map(|i| BigInt::from((i * 7_919) % 3_001))
+            .collect();
+
+        let values = calc_many(&start_nums, &indices);
+        for (index, value) in indices.iter().zip(&values) {
+            assert_eq!(value, &nth_term(&start_nums, index.to_usize().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_negative_indices() {
+        let start_nums = (BigInt::from(2), BigInt::from(1)); // Lucas: L(−n) = (−1)^n·L(n)
+        let indices: Vec<BigInt> = [-3, -2, 2, 3].into_iter().map(BigInt::from).collect();
+        let expected: Vec<BigInt> = [-4, 3, 3, 4].into_iter().map(BigInt::from).collect();
+        assert_eq!(calc_many(&start_nums, &indices), expected);
+    }
+
+    #[test]
+    fn test_walker_goes_back() {
+        let start_nums = (BigInt::from(3), BigInt::from(-7));
+        let mut walker = TermWalker::new(start_nums.clone());
+        for index in [40usize, 45, 1_000, 2, 3, 500] {
+            assert_eq!(walker.term(&BigInt::from(index)), nth_term(&start_nums, index));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        assert!(calc_many(&start_nums, &[]).is_empty());
+    }
+}
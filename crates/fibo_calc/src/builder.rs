@@ -1,3 +1,11 @@
+use crate::aggregate::AggregateMode;
+use crate::checkpoint::CheckpointConfig;
+use crate::polynomial::PolynomialKind;
+use crate::search::FirstMatches;
+use crate::selection::IndexSelection;
+use crate::sink::{BoxedSink, ResultSink};
+use crate::transform::{Transform, TransformStage};
+use crate::value_range::ValueRange;
 use num_bigint::BigInt;
 use std::ops::Range;
 
@@ -8,7 +16,16 @@ pub type FilterFn = Box<dyn Fn(&BigInt) -> bool + Send + Sync>;
 pub struct FiboBuilder {
     start_nums: Option<(BigInt, BigInt)>,
     range_by_index: Option<Range<usize>>,
+    index_selection: Option<IndexSelection>,
+    value_range: Option<ValueRange>,
+    search: Option<FirstMatches>,
     other_filters: Vec<FilterFn>,
+    aggregate: Option<AggregateMode>,
+    transforms: Vec<(TransformStage, Transform)>,
+    verify: bool,
+    checkpoint: Option<CheckpointConfig>,
+    sink: Option<BoxedSink>,
+    polynomials: Option<PolynomialKind>,
 }
 
 impl FiboBuilder {
@@ -16,16 +33,76 @@ impl FiboBuilder {
         &self.other_filters
     }
 
+    pub fn get_transforms(&self) -> &[(TransformStage, Transform)] {
+        &self.transforms
+    }
+
+    /// Transforms of one stage in the order they were added.
+    pub fn get_transforms_at(&self, stage: TransformStage) -> Vec<Transform> {
+        self.transforms
+            .iter()
+            .filter(|(s, _)| *s == stage)
+            .map(|(_, transform)| transform.clone())
+            .collect()
+    }
+
     pub fn get_range_by_id(&self) -> Option<Range<usize>> {
         self.range_by_index.clone()
     }
 
+    pub fn get_aggregate(&self) -> Option<AggregateMode> {
+        self.aggregate.clone()
+    }
+
+    pub fn get_index_selection(&self) -> Option<IndexSelection> {
+        self.index_selection.clone()
+    }
+
+    pub fn get_value_range(&self) -> Option<ValueRange> {
+        self.value_range.clone()
+    }
+
+    pub fn get_search(&self) -> Option<FirstMatches> {
+        self.search.clone()
+    }
+
+    /// The index selection, falling back to the plain range.
+    pub fn get_effective_selection(&self) -> Option<IndexSelection> {
+        self.get_index_selection()
+            .or_else(|| self.get_range_by_id().map(IndexSelection::range))
+    }
+
+    pub fn get_verify(&self) -> bool {
+        self.verify
+    }
+
+    pub fn get_checkpoint(&self) -> Option<CheckpointConfig> {
+        self.checkpoint.clone()
+    }
+
+    pub fn has_sink(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Moves the sink out, as it cannot be cloned.
+    pub fn take_sink(&mut self) -> Option<BoxedSink> {
+        self.sink.take()
+    }
+
+    pub fn get_polynomials(&self) -> Option<PolynomialKind> {
+        self.polynomials
+    }
+
     pub fn get_start_nums(&self) -> Option<(BigInt, BigInt)> {
         self.start_nums.clone()
     }
 
     pub fn is_none_filter(&self) -> bool {
-        self.range_by_index.is_none() && self.other_filters.is_empty()
+        self.range_by_index.is_none()
+            && self.index_selection.is_none()
+            && self.value_range.is_none()
+            && self.search.is_none()
+            && self.other_filters.is_empty()
     }
 
     pub fn add_filter(
@@ -35,11 +112,75 @@ impl FiboBuilder {
         self
     }
 
+    /// Transforms run in insertion order within their stage.
+    pub fn add_transform(&mut self, stage: TransformStage, transform: Transform) -> &mut Self {
+        self.transforms.push((stage, transform));
+        self
+    }
+
     pub fn set_range_by_id(&mut self, new_range: Option<Range<usize>>) -> &mut Self {
         self.range_by_index = new_range;
         self
     }
 
+    pub fn set_aggregate(&mut self, aggregate: Option<AggregateMode>) -> &mut Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    /// Takes precedence over `set_range_by_id` when both are set.
+    pub fn set_index_selection(&mut self, selection: Option<IndexSelection>) -> &mut Self {
+        self.index_selection = selection;
+        self
+    }
+
+    /// Selects the terms by value instead of index; takes precedence over
+    /// `set_index_selection` and `set_range_by_id`.
+    pub fn set_value_range(&mut self, value_range: Option<ValueRange>) -> &mut Self {
+        self.value_range = value_range;
+        self
+    }
+
+    /// Scans from `search.start` until enough terms pass the filters instead
+    /// of using any range or selection. Before-filter transforms run on the
+    /// scanned terms one at a time.
+    pub fn set_search(&mut self, search: Option<FirstMatches>) -> &mut Self {
+        self.search = search;
+        self
+    }
+
+    /// Checks every generated term with `Verifier`, in searches, aggregates
+    /// and sinks too, and sends a `FiboTaskResult::Verification` before the
+    /// result.
+    pub fn set_verify(&mut self, verify: bool) -> &mut Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Saves the progress of a range or a single term every
+    /// `checkpoint.interval`, so `FiboCalc::resume` can continue it.
+    pub fn set_checkpoint(&mut self, checkpoint: Option<CheckpointConfig>) -> &mut Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Streams the terms of a range, selection or value range that pass the
+    /// filters into `sink` while generating them, instead of collecting a
+    /// `FiboTaskResult::Result`. The task answers with an error when the
+    /// builder also has transforms, an aggregate, a search or a checkpoint.
+    pub fn set_sink(&mut self, sink: Option<impl ResultSink + 'static>) -> &mut Self {
+        self.sink = sink.map(|sink| Box::new(sink) as BoxedSink);
+        self
+    }
+
+    /// Produces the polynomials of the range or selection instead of numbers,
+    /// sent as `FiboTaskResult::Polynomials`. Start numbers do not apply and
+    /// the filters see Pₙ(1), the Fibonacci or Lucas number.
+    pub fn set_polynomials(&mut self, kind: Option<PolynomialKind>) -> &mut Self {
+        self.polynomials = kind;
+        self
+    }
+
     pub fn set_start_nums(&mut self, start_num: Option<(BigInt, BigInt)>) -> &mut Self {
         self.start_nums = start_num;
         self
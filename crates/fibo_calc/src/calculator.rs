@@ -1,12 +1,48 @@
+use crate::batch;
+use crate::checkpoint::{self, Checkpoint, CheckpointConfig, CheckpointJob};
 use crate::builder::FiboBuilder;
+use crate::implementation::doubling::{fibo_pair, jump_pair};
 use crate::implementation::matmul::MatmulFibo;
 use crate::task;
 use crate::task::FiboTaskReceiver;
 use num_bigint::BigInt;
 use crate::implementation::lineal::LinealFibo;
+use crate::closed_form::ClosedForm;
+use crate::digits::DigitSummary;
+use crate::iter::{FiboIter, FiboSequence};
+use crate::polynomial::PolynomialKind;
+use crate::ratio::RatioConvergent;
+use crate::set_ops::{self, SetOperation};
+use crate::verify::{Verification, Verifier};
+use crate::word::FiboWord;
+use num_traits::{One, Zero};
+use std::path::Path;
 
-pub trait ImplementationFibo: Iterator<Item = BigInt> {
+/// Term generator driven by the background task. After `new(Some((a, b)))`
+/// it yields G(2), G(3), …; the task emits G(0) and G(1) itself.
+pub trait ImplementationFibo: Iterator<Item = BigInt> + Sized {
     fn new(start_nums: Option<(BigInt, BigInt)>) -> Self;
+
+    /// Moves a fresh generator past the n − 2 items before G(n), so the next
+    /// one is G(n). The default steps through them.
+    fn jump_to(&mut self, n: usize) {
+        for _ in 2..n {
+            self.next();
+        }
+    }
+
+    /// G(n) on its own, by default from a fresh generator jumped to n.
+    fn term(start_nums: (BigInt, BigInt), n: usize) -> BigInt {
+        match n {
+            0 => start_nums.0,
+            1 => start_nums.1,
+            _ => {
+                let mut fibo = Self::new(Some(start_nums));
+                fibo.jump_to(n);
+                fibo.next().unwrap_or_default()
+            }
+        }
+    }
 }
 
 pub struct FiboCalc {
@@ -19,16 +55,152 @@ impl FiboCalc {
     }
 
     pub fn calc_background(self) -> FiboTaskReceiver {
+        self.calc_background_with::<LinealFibo>()
+    }
+
+    /// Same pipeline as `calc_background` with a user-supplied generator. The
+    /// work runs on Tokio's blocking pool, the receiver stays async.
+    pub fn calc_background_with<I: ImplementationFibo + Send + 'static>(self) -> FiboTaskReceiver {
         let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
 
-        tokio::spawn(async move {
-            task::calculate_fibo_task::<LinealFibo>(self.builder, sender).await;
+        tokio::task::spawn_blocking(move || {
+            task::calculate_fibo_task::<I>(self.builder, sender);
         });
 
         receiver
     }
 
+    /// Lazy counterpart of `calc_background` over the builder's range.
+    pub fn calc_iter(self) -> Result<FiboIter, String> {
+        FiboIter::try_from(&self.builder)
+    }
+
+    /// Every term from index 0 on, for builders without a range.
+    pub fn calc_sequence(self) -> Result<FiboSequence, String> {
+        FiboSequence::try_from(&self.builder)
+    }
+
+    /// G(n − 1) for n ≥ 2: unlike the rest of `FiboCalc` this takes a 1-based
+    /// position, `calc_term` is the 0-based form.
     pub fn calc_one_number(self, n: BigInt) -> BigInt {
         MatmulFibo::new(self.builder.get_start_nums()).calc_one(n)
     }
+
+    /// `calc_one_number` as a background task for n ≥ 1, checkpointed by fast
+    /// doubling when the builder has a checkpoint config. Sends progress, any
+    /// `FiboTaskResult::CheckpointFailed`, then the term as a `Result`.
+    pub fn calc_one_background(self, n: BigInt) -> FiboTaskReceiver {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        tokio::task::spawn_blocking(move || {
+            task::calculate_one_task(self.builder, n, None, sender);
+        });
+        receiver
+    }
+
+    /// Continues the calculation checkpointed at `path`. Filters, transforms
+    /// and the aggregate come from this builder since they are not saved; it
+    /// keeps saving to `path` at the builder's checkpoint interval.
+    pub fn resume(mut self, path: impl AsRef<Path>) -> Result<FiboTaskReceiver, String> {
+        let path = path.as_ref();
+        let checkpoint = Checkpoint::load(path)?;
+        let results = match &checkpoint.job {
+            CheckpointJob::Range {
+                range,
+                next,
+                offset,
+                ..
+            } => {
+                let results = checkpoint::read_results(path, *offset)?;
+                if range.start + results.len() != *next {
+                    return Err(format!(
+                        "Checkpoint expects terms up to {} but its results end at {}",
+                        next,
+                        range.start + results.len()
+                    ));
+                }
+                results
+            }
+            CheckpointJob::One { .. } => Vec::new(),
+        };
+
+        let mut config = CheckpointConfig::new(path);
+        if let Some(own) = self.builder.get_checkpoint() {
+            config.interval = own.interval;
+        }
+        self.builder.set_checkpoint(Some(config));
+
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        tokio::task::spawn_blocking(move || {
+            task::resume_fibo_task::<LinealFibo>(self.builder, checkpoint, results, sender);
+        });
+        Ok(receiver)
+    }
+
+    /// G(n) for a 0-based index n, as `calc_many`, `calc_digits` and the
+    /// ranges use, by fast doubling. Negative n follow the reverse recurrence.
+    pub fn calc_term(self, n: BigInt) -> BigInt {
+        jump_pair(&self.start_nums_or_default(), &fibo_pair(&n)).0
+    }
+
+    /// `calc_term` through a user-supplied generator's `term` hook, without
+    /// checkpoints.
+    pub fn calc_term_with<I: ImplementationFibo>(self, n: usize) -> BigInt {
+        I::term(self.start_nums_or_default(), n)
+    }
+
+    /// `calc_term` checked by fast doubling modulo random primes.
+    pub fn calc_term_verified(self, n: BigInt) -> (BigInt, Verification) {
+        let verifier = Verifier::new(self.start_nums_or_default());
+        let value = self.calc_term(n.clone());
+        let verification = verifier.check_one(&n, &value);
+        (value, verification)
+    }
+
+    /// G(n) for many 0-based indices in input order.
+    pub fn calc_many<N: Into<BigInt>>(self, indices: impl IntoIterator<Item = N>) -> Vec<BigInt> {
+        let indices: Vec<BigInt> = indices.into_iter().map(Into::into).collect();
+        batch::calc_many(&self.start_nums_or_default(), &indices)
+    }
+
+    /// Polynomials at the builder's indices, computed like `calc_background`
+    /// and sent as `FiboTaskResult::Polynomials`. See `set_polynomials`.
+    pub fn calc_polynomials(mut self, kind: PolynomialKind) -> FiboTaskReceiver {
+        self.builder.set_polynomials(Some(kind));
+        self.calc_background()
+    }
+
+    pub fn calc_words(self, alphabet: (char, char)) -> Vec<FiboWord> {
+        match self.builder.get_effective_selection() {
+            Some(selection) => selection
+                .iter()
+                .map(|index| FiboWord::new(index, alphabet))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Values of this builder's sequence combined with `other`'s up to |x| ≤ `bound`.
+    pub fn calc_set_operation(
+        self, other: &FiboBuilder, operation: SetOperation, bound: &BigInt,
+    ) -> Vec<BigInt> {
+        set_ops::combine_sequences(&self.builder, other, operation, bound)
+    }
+
+    pub fn calc_closed_form(self) -> ClosedForm {
+        ClosedForm::new(&self.start_nums_or_default())
+    }
+
+    pub fn calc_ratio(self, n: usize, digits: usize) -> Option<RatioConvergent> {
+        RatioConvergent::new(&self.start_nums_or_default(), n, digits)
+    }
+
+    pub fn calc_digits(self, n: BigInt, k: usize) -> Result<DigitSummary, String> {
+        DigitSummary::new(&self.start_nums_or_default(), &n, k)
+    }
+
+    fn start_nums_or_default(&self) -> (BigInt, BigInt) {
+        self.builder
+            .get_start_nums()
+            .unwrap_or((BigInt::zero(), BigInt::one()))
+    }
 }
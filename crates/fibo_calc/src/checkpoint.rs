@@ -0,0 +1,491 @@
+use crate::implementation::doubling::{continue_fibo_pair, jump_pair};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Saves at most this often unless told otherwise.
+pub const DEFAULT_CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+const HEADER: &str = "fibo-checkpoint 1";
+
+/// Where and how often a running calculation saves its progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointConfig {
+    pub path: PathBuf,
+    pub interval: Duration,
+}
+
+impl CheckpointConfig {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: DEFAULT_CHECKPOINT_INTERVAL,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointJob {
+    /// Index range with terms before `next` already stored in the results
+    /// file up to `offset` bytes; `pair` is (G(next − 2), G(next − 1)).
+    Range {
+        range: Range<usize>,
+        next: usize,
+        pair: (BigInt, BigInt),
+        offset: u64,
+    },
+    /// `calc_one_background(n)`: the fast-doubling pair for the bits of n − 1
+    /// above the lowest `bits_left`.
+    One {
+        n: BigInt,
+        bits_left: u64,
+        pair: (BigInt, BigInt),
+    },
+}
+
+/// Last saved state of an interrupted calculation. Filters and transforms are
+/// closures and are not saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub start_nums: (BigInt, BigInt),
+    pub job: CheckpointJob,
+}
+
+impl Checkpoint {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read checkpoint {}: {}", path.display(), e))?;
+
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(format!("{} is not a checkpoint", path.display()));
+        }
+        let fields: HashMap<&str, &str> = lines.filter_map(|line| line.split_once(' ')).collect();
+
+        let start_nums = parse_pair(&fields, "start")?;
+        let job = match field(&fields, "job")? {
+            "range" => CheckpointJob::Range {
+                range: parse_number(&fields, "range_start")?..parse_number(&fields, "range_end")?,
+                next: parse_number(&fields, "next")?,
+                pair: parse_pair(&fields, "pair")?,
+                offset: parse_number(&fields, "offset")?,
+            },
+            "one" => CheckpointJob::One {
+                n: parse_big(field(&fields, "n")?)?,
+                bits_left: parse_number(&fields, "bits_left")?,
+                pair: parse_pair(&fields, "pair")?,
+            },
+            other => return Err(format!("Unknown checkpoint job '{}'", other)),
+        };
+
+        Ok(Self { start_nums, job })
+    }
+
+    /// Writes next to `path` and renames, so a crash leaves the old checkpoint.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let mut text = format!("{}\nstart {}\n", HEADER, format_pair(&self.start_nums));
+        match &self.job {
+            CheckpointJob::Range {
+                range,
+                next,
+                pair,
+                offset,
+            } => {
+                text += &format!(
+                    "job range\nrange_start {}\nrange_end {}\nnext {}\npair {}\noffset {}\n",
+                    range.start,
+                    range.end,
+                    next,
+                    format_pair(pair),
+                    offset
+                )
+            }
+            CheckpointJob::One { n, bits_left, pair } => {
+                text += &format!(
+                    "job one\nn {}\nbits_left {}\npair {}\n",
+                    n.to_str_radix(16),
+                    bits_left,
+                    format_pair(pair)
+                )
+            }
+        }
+
+        let temporary = with_suffix(path, ".tmp");
+        fs::write(&temporary, text)
+            .and_then(|_| fs::rename(&temporary, path))
+            .map_err(|e| format!("Cannot write checkpoint {}: {}", path.display(), e))
+    }
+
+    /// Deletes a checkpoint and its results file.
+    pub fn remove(path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(results_path(path));
+    }
+}
+
+/// Terms saved by a range checkpoint live next to it in this file.
+pub fn results_path(path: impl AsRef<Path>) -> PathBuf {
+    with_suffix(path.as_ref(), ".results")
+}
+
+/// Terms in the results file up to `offset` bytes, ignoring anything a
+/// crash left after it.
+pub(crate) fn read_results(path: &Path, offset: u64) -> Result<Vec<BigInt>, String> {
+    if offset == 0 {
+        return Ok(Vec::new());
+    }
+    let file = File::open(results_path(path))
+        .map_err(|e| format!("Cannot read checkpoint results: {}", e))?;
+    let mut reader = BufReader::new(file.take(offset));
+    let mut results = Vec::new();
+    let mut length = [0; 8];
+
+    while reader.read_exact(&mut length).is_ok() {
+        // The length is untrusted: grow the buffer only as bytes actually arrive
+        let length = u64::from_le_bytes(length);
+        let mut bytes = Vec::new();
+        let read = (&mut reader)
+            .take(length)
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Cannot read checkpoint results: {}", e))?;
+        if read as u64 != length {
+            return Err(format!(
+                "Checkpoint results are cut short: a term needs {} bytes",
+                length
+            ));
+        }
+        results.push(BigInt::from_signed_bytes_le(&bytes));
+    }
+    Ok(results)
+}
+
+/// Appends terms after `offset` bytes and returns the new length.
+fn append_results(path: &Path, offset: u64, terms: &[BigInt]) -> std::io::Result<u64> {
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(results_path(path))?;
+    file.set_len(offset)?;
+
+    let mut writer = BufWriter::new(file);
+    let mut length = offset;
+    writer.seek(SeekFrom::Start(offset))?;
+    for term in terms {
+        let bytes = term.to_signed_bytes_le();
+        writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        writer.write_all(&bytes)?;
+        length += 8 + bytes.len() as u64;
+    }
+    writer.flush()?;
+    Ok(length)
+}
+
+/// Saves a range calculation whenever the interval has passed.
+pub(crate) struct RangeCheckpointer {
+    config: CheckpointConfig,
+    start_nums: (BigInt, BigInt),
+    range: Range<usize>,
+    /// Terms already in the results file
+    saved: usize,
+    offset: u64,
+    last_save: Instant,
+}
+
+impl RangeCheckpointer {
+    pub(crate) fn new(
+        config: CheckpointConfig, start_nums: (BigInt, BigInt), range: Range<usize>, saved: usize,
+        offset: u64,
+    ) -> Self {
+        Self {
+            config,
+            start_nums,
+            range,
+            saved,
+            offset,
+            last_save: Instant::now(),
+        }
+    }
+
+    /// `terms` holds G(range.start), … in order, the last two of them generated.
+    pub(crate) fn maybe_save(&mut self, terms: &[BigInt]) -> Result<(), String> {
+        if self.last_save.elapsed() < self.config.interval || terms.len() < 2 {
+            return Ok(());
+        }
+        self.last_save = Instant::now();
+
+        let offset = append_results(&self.config.path, self.offset, &terms[self.saved..])
+            .map_err(|e| format!("Cannot write checkpoint results: {}", e))?;
+        self.offset = offset;
+        self.saved = terms.len();
+
+        let checkpoint = Checkpoint {
+            start_nums: self.start_nums.clone(),
+            job: CheckpointJob::Range {
+                range: self.range.clone(),
+                next: self.range.start + terms.len(),
+                pair: (
+                    terms[terms.len() - 2].clone(),
+                    terms[terms.len() - 1].clone(),
+                ),
+                offset,
+            },
+        };
+        checkpoint.save(&self.config.path)
+    }
+
+    pub(crate) fn finish(self) {
+        Checkpoint::remove(&self.config.path);
+    }
+}
+
+/// G(n − 1) like `calc_one_background` for n ≥ 1, by fast doubling that saves its
+/// pair every interval when configured and can pick up from
+/// `resumed` = (bits_left, pair). A failed save goes to `on_failed_save` and
+/// the calculation carries on.
+pub(crate) fn calc_one(
+    start_nums: (BigInt, BigInt), n: BigInt, config: Option<&CheckpointConfig>,
+    resumed: Option<(u64, (BigInt, BigInt))>, mut on_bit: impl FnMut(u64, u64),
+    mut on_failed_save: impl FnMut(String),
+) -> BigInt {
+    let m: BigInt = &n - 1;
+    let (bits_left, pair) = resumed.unwrap_or_else(|| (m.bits(), (BigInt::zero(), BigInt::one())));
+    let mut last_save = Instant::now();
+
+    let gap_pair = continue_fibo_pair(&m, bits_left, pair, |bits_left, pair| {
+        on_bit(m.bits() - bits_left, m.bits());
+        let Some(config) = config.filter(|config| last_save.elapsed() >= config.interval) else {
+            return;
+        };
+        last_save = Instant::now();
+        let checkpoint = Checkpoint {
+            start_nums: start_nums.clone(),
+            job: CheckpointJob::One {
+                n: n.clone(),
+                bits_left,
+                pair: pair.clone(),
+            },
+        };
+        if let Err(e) = checkpoint.save(&config.path) {
+            on_failed_save(e);
+        }
+    });
+
+    if let Some(config) = config {
+        Checkpoint::remove(&config.path);
+    }
+    jump_pair(&start_nums, &gap_pair).0
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn field<'a>(fields: &HashMap<&str, &'a str>, key: &str) -> Result<&'a str, String> {
+    fields
+        .get(key)
+        .copied()
+        .ok_or_else(|| format!("Checkpoint has no '{}'", key))
+}
+
+fn parse_number<T: std::str::FromStr>(
+    fields: &HashMap<&str, &str>, key: &str,
+) -> Result<T, String> {
+    field(fields, key)?
+        .parse()
+        .map_err(|_| format!("Invalid checkpoint '{}'", key))
+}
+
+fn parse_big(text: &str) -> Result<BigInt, String> {
+    BigInt::parse_bytes(text.as_bytes(), 16).ok_or_else(|| format!("Invalid number '{}'", text))
+}
+
+fn parse_pair(fields: &HashMap<&str, &str>, key: &str) -> Result<(BigInt, BigInt), String> {
+    let (a, b) = field(fields, key)?
+        .split_once(' ')
+        .ok_or_else(|| format!("Invalid checkpoint '{}'", key))?;
+    Ok((parse_big(a)?, parse_big(b)?))
+}
+
+fn format_pair((a, b): &(BigInt, BigInt)) -> String {
+    format!("{} {}", a.to_str_radix(16), b.to_str_radix(16))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("fibo_calc_{}_{}", name, std::process::id()))
+    }
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    #[test_case(CheckpointJob::Range {
+        range: 10..5_000,
+        next: 1_234,
+        pair: (nth_term(&seeds(-3, 8), 1_232), nth_term(&seeds(-3, 8), 1_233)),
+        offset: 98_765,
+    }; "range")]
+    #[test_case(CheckpointJob::One {
+        n: BigInt::from(10).pow(9),
+        bits_left: 17,
+        pair: seeds(-5, 0),
+    }; "one")]
+    fn test_save_and_load(job: CheckpointJob) {
+        let path = temp_path("round_trip");
+        let checkpoint = Checkpoint {
+            start_nums: seeds(-3, 8),
+            job,
+        };
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path), Ok(checkpoint));
+
+        Checkpoint::remove(&path);
+        assert!(Checkpoint::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_results_file_ignores_tail_after_offset() {
+        let path = temp_path("results");
+        let terms: Vec<BigInt> = (0..50).map(|n| nth_term(&seeds(2, -1), n)).collect();
+
+        let offset = append_results(&path, 0, &terms[..30]).unwrap();
+        append_results(&path, offset, &terms[30..]).unwrap();
+        assert_eq!(read_results(&path, offset).unwrap(), terms[..30]);
+
+        let offset = append_results(&path, offset, &terms[30..40]).unwrap();
+        assert_eq!(read_results(&path, offset).unwrap(), terms[..40]);
+        Checkpoint::remove(&path);
+    }
+
+    #[test]
+    fn test_results_file_corrupt_length() {
+        let path = temp_path("corrupt_results");
+        let mut bytes = u64::MAX.to_le_bytes().to_vec();
+        bytes.extend([1, 2, 3]);
+        fs::write(results_path(&path), &bytes).unwrap();
+        assert_eq!(
+            read_results(&path, bytes.len() as u64),
+            Err(format!(
+                "Checkpoint results are cut short: a term needs {} bytes",
+                u64::MAX
+            ))
+        );
+        Checkpoint::remove(&path);
+    }
+
+    #[test]
+    fn test_invalid_checkpoint() {
+        let path = temp_path("invalid");
+        fs::write(&path, "something else\n").unwrap();
+        assert!(
+            Checkpoint::load(&path)
+                .unwrap_err()
+                .contains("is not a checkpoint")
+        );
+
+        fs::write(&path, format!("{}\nstart 0 1\njob range\n", HEADER)).unwrap();
+        assert_eq!(
+            Checkpoint::load(&path),
+            Err(String::from("Checkpoint has no 'range_start'"))
+        );
+        Checkpoint::remove(&path);
+    }
+
+    #[test]
+    fn test_calc_one_resumes_from_pair() {
+        let path = temp_path("calc_one");
+        let config = CheckpointConfig {
+            path: path.clone(),
+            interval: Duration::ZERO,
+        };
+        let start_nums = seeds(2, 1);
+        let n = BigInt::from(5_001);
+
+        let mut saved = None;
+        let full = calc_one(
+            start_nums.clone(),
+            n.clone(),
+            Some(&config),
+            None,
+            |done, total| {
+                if done == total / 2 {
+                    saved = Checkpoint::load(&path).ok();
+                }
+            },
+            |e| panic!("{}", e),
+        );
+        assert_eq!(full, nth_term(&start_nums, 5_000));
+        assert!(Checkpoint::load(&path).is_err());
+
+        let Some(CheckpointJob::One {
+            bits_left, pair, ..
+        }) = saved.map(|c| c.job)
+        else {
+            panic!("No checkpoint was saved");
+        };
+        let resumed = calc_one(
+            start_nums,
+            n,
+            Some(&config),
+            Some((bits_left, pair)),
+            |_, _| {},
+            |e| panic!("{}", e),
+        );
+        assert_eq!(resumed, full);
+    }
+
+    #[test]
+    fn test_calc_one_reports_failed_saves() {
+        let config = CheckpointConfig {
+            path: temp_path("missing_dir").join("calc_one"),
+            interval: Duration::ZERO,
+        };
+        let start_nums = seeds(3, -7);
+
+        let mut failures = Vec::new();
+        let value = calc_one(
+            start_nums.clone(),
+            BigInt::from(300),
+            Some(&config),
+            None,
+            |_, _| {},
+            |e| failures.push(e),
+        );
+        assert_eq!(value, nth_term(&start_nums, 299));
+        assert!(!failures.is_empty());
+        assert!(failures[0].starts_with("Cannot write checkpoint"));
+    }
+
+    #[test]
+    fn test_range_checkpointer_reports_failed_saves() {
+        let config = CheckpointConfig {
+            path: temp_path("missing_dir").join("range"),
+            interval: Duration::ZERO,
+        };
+        let start_nums = seeds(1, 1);
+        let terms: Vec<BigInt> = (0..20).map(|n| nth_term(&start_nums, n)).collect();
+
+        let mut checkpointer = RangeCheckpointer::new(config, start_nums, 0..20, 0, 0);
+        assert!(
+            checkpointer
+                .maybe_save(&terms)
+                .unwrap_err()
+                .starts_with("Cannot write checkpoint results")
+        );
+    }
+}
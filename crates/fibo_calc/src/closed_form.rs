@@ -0,0 +1,265 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+/// r + s·√5, an element of Q(√5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Surd {
+    pub rational: BigRational,
+    pub radical: BigRational,
+}
+
+impl Surd {
+    pub fn new(rational: BigRational, radical: BigRational) -> Self {
+        Self { rational, radical }
+    }
+
+    /// φ = 1/2 + 1/2·√5
+    pub fn phi() -> Self {
+        let half = BigRational::new(BigInt::one(), BigInt::from(2));
+        Self::new(half.clone(), half)
+    }
+
+    /// ψ = 1/2 − 1/2·√5
+    pub fn psi() -> Self {
+        Self::phi().conjugate()
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.rational.clone(), -&self.radical)
+    }
+
+    pub fn pow(&self, mut exponent: usize) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::new(BigRational::one(), BigRational::zero());
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl Add for &Surd {
+    type Output = Surd;
+
+    fn add(self, other: &Surd) -> Surd {
+        Surd::new(
+            &self.rational + &other.rational,
+            &self.radical + &other.radical,
+        )
+    }
+}
+
+impl Mul for &Surd {
+    type Output = Surd;
+
+    /// (r + s√5)(t + u√5) = (rt + 5su) + (ru + st)√5
+    fn mul(self, other: &Surd) -> Surd {
+        let five = BigRational::from(BigInt::from(5));
+        Surd::new(
+            &self.rational * &other.rational + five * &self.radical * &other.radical,
+            &self.rational * &other.radical + &self.radical * &other.rational,
+        )
+    }
+}
+
+impl Display for Surd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.rational.is_zero(), self.radical.is_zero()) {
+            (_, true) => write!(f, "{}", self.rational),
+            (true, false) => write!(f, "{}√5", coefficient(&self.radical)),
+            (false, false) => {
+                let sign = if self.radical.is_negative() { "-" } else { "+" };
+                write!(
+                    f,
+                    "{} {} {}√5",
+                    self.rational,
+                    sign,
+                    coefficient(&self.radical.abs())
+                )
+            }
+        }
+    }
+}
+
+/// Decomposition of G(0) = a, G(1) = b, G(n) = G(n−1) + G(n−2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosedForm {
+    pub start_nums: (BigInt, BigInt),
+    /// α in G(n) = α·φⁿ + β·ψⁿ
+    pub phi_coefficient: Surd,
+    /// β, always the conjugate of α
+    pub psi_coefficient: Surd,
+}
+
+impl ClosedForm {
+    /// α = a/2 + (2b − a)/10·√5 solves α + β = a, αφ + βψ = b.
+    pub fn new(start_nums: &(BigInt, BigInt)) -> Self {
+        let (a, b) = start_nums;
+        let phi_coefficient = Surd::new(
+            BigRational::new(a.clone(), BigInt::from(2)),
+            BigRational::new(b * 2 - a, BigInt::from(10)),
+        );
+
+        Self {
+            start_nums: start_nums.clone(),
+            psi_coefficient: phi_coefficient.conjugate(),
+            phi_coefficient,
+        }
+    }
+
+    /// G(n) = a·F(n−1) + b·F(n)
+    pub fn linear_combination(&self) -> String {
+        let (a, b) = &self.start_nums;
+        format!("G(n) = {}", combination(&[(a, "F(n-1)"), (b, "F(n)")]))
+    }
+
+    /// G(n) = α·φⁿ + β·ψⁿ
+    pub fn binet(&self) -> String {
+        format!(
+            "G(n) = ({})·φⁿ + ({})·ψⁿ",
+            self.phi_coefficient, self.psi_coefficient
+        )
+    }
+
+    /// Numerator coefficients (a, b − a) of Σ G(n)·xⁿ = (a + (b − a)x) / (1 − x − x²).
+    pub fn generating_numerator(&self) -> (BigInt, BigInt) {
+        let (a, b) = &self.start_nums;
+        (a.clone(), b - a)
+    }
+
+    pub fn generating_function(&self) -> String {
+        let (constant, linear) = self.generating_numerator();
+        format!(
+            "Σ G(n)·xⁿ = ({}) / (1 - x - x²)",
+            combination(&[(&constant, ""), (&linear, "x")])
+        )
+    }
+
+    /// Exact G(n) from the Binet form, the irrational parts cancel.
+    pub fn evaluate(&self, n: usize) -> BigInt {
+        let value = &(&self.phi_coefficient * &Surd::phi().pow(n))
+            + &(&self.psi_coefficient * &Surd::psi().pow(n));
+        debug_assert!(value.radical.is_zero() && value.rational.is_integer());
+        value.rational.to_integer()
+    }
+}
+
+impl Display for ClosedForm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.linear_combination())?;
+        writeln!(f, "{}", self.binet())?;
+        write!(f, "{}", self.generating_function())
+    }
+}
+
+/// Coefficient in front of a symbol: "" for 1, "-" for −1, "3/10·" otherwise.
+fn coefficient(value: &BigRational) -> String {
+    if value.is_one() {
+        String::new()
+    } else if (-value).is_one() {
+        String::from("-")
+    } else {
+        format!("{}·", value)
+    }
+}
+
+/// Sum of `coefficient·symbol` terms skipping zeros, e.g. "2 - 3x".
+fn combination(terms: &[(&BigInt, &str)]) -> String {
+    let mut text = String::new();
+
+    for (value, symbol) in terms.iter().filter(|(value, _)| !value.is_zero()) {
+        let magnitude = value.abs();
+        let magnitude = match (magnitude.is_one(), symbol.is_empty()) {
+            (true, false) => String::new(),
+            (_, true) => magnitude.to_string(),
+            (false, false) => format!("{}·", magnitude),
+        };
+
+        let sign = match (text.is_empty(), value.is_negative()) {
+            (true, true) => "-",
+            (true, false) => "",
+            (false, true) => " - ",
+            (false, false) => " + ",
+        };
+        text.push_str(&format!("{}{}{}", sign, magnitude, symbol));
+    }
+
+    if text.is_empty() {
+        text.push('0');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    #[test_case(seeds(0, 1), "G(n) = F(n)"; "classic")]
+    #[test_case(seeds(2, 1), "G(n) = 2·F(n-1) + F(n)"; "lucas")]
+    #[test_case(seeds(-4, 7), "G(n) = -4·F(n-1) + 7·F(n)"; "mixed")]
+    #[test_case(seeds(1, -1), "G(n) = F(n-1) - F(n)"; "negative second")]
+    #[test_case(seeds(0, 0), "G(n) = 0"; "zero")]
+    fn test_linear_combination(start_nums: (BigInt, BigInt), expected: &str) {
+        assert_eq!(ClosedForm::new(&start_nums).linear_combination(), expected);
+    }
+
+    #[test_case(seeds(0, 1), "G(n) = (1/5·√5)·φⁿ + (-1/5·√5)·ψⁿ"; "classic")]
+    #[test_case(seeds(2, 1), "G(n) = (1)·φⁿ + (1)·ψⁿ"; "lucas")]
+    #[test_case(seeds(3, 1), "G(n) = (3/2 - 1/10·√5)·φⁿ + (3/2 + 1/10·√5)·ψⁿ"; "custom")]
+    fn test_binet(start_nums: (BigInt, BigInt), expected: &str) {
+        assert_eq!(ClosedForm::new(&start_nums).binet(), expected);
+    }
+
+    #[test_case(seeds(0, 1), "Σ G(n)·xⁿ = (x) / (1 - x - x²)"; "classic")]
+    #[test_case(seeds(2, 1), "Σ G(n)·xⁿ = (2 - x) / (1 - x - x²)"; "lucas")]
+    #[test_case(seeds(3, 3), "Σ G(n)·xⁿ = (3) / (1 - x - x²)"; "no linear term")]
+    fn test_generating_function(start_nums: (BigInt, BigInt), expected: &str) {
+        assert_eq!(ClosedForm::new(&start_nums).generating_function(), expected);
+    }
+
+    #[test_case(seeds(0, 1); "classic")]
+    #[test_case(seeds(2, 1); "lucas")]
+    #[test_case(seeds(-17, 5); "mixed")]
+    fn test_binet_evaluates_to_terms(start_nums: (BigInt, BigInt)) {
+        let closed_form = ClosedForm::new(&start_nums);
+        for n in 0..60 {
+            assert_eq!(closed_form.evaluate(n), nth_term(&start_nums, n));
+        }
+    }
+
+    #[test]
+    fn test_generating_function_series() {
+        // (a + (b − a)x) / (1 − x − x²) expanded by long division
+        let start_nums = seeds(5, -2);
+        let (constant, linear) = ClosedForm::new(&start_nums).generating_numerator();
+        let mut series = vec![constant.clone(), linear + &constant];
+        for n in 2..30 {
+            let next = &series[n - 1] + &series[n - 2];
+            series.push(next);
+        }
+
+        for (n, value) in series.iter().enumerate() {
+            assert_eq!(value, &nth_term(&start_nums, n));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let text = ClosedForm::new(&seeds(2, 1)).to_string();
+        assert_eq!(text.lines().count(), 3);
+        assert!(text.starts_with("G(n) = 2·F(n-1) + F(n)\n"));
+    }
+}
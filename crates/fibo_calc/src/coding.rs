@@ -0,0 +1,321 @@
+//! Fibonacci coding: the Zeckendorf digits of a positive integer from F(2)
+//! upwards, followed by an extra `1`. No Zeckendorf representation has two
+//! adjacent ones, so the final "11" delimits each codeword.
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed};
+use std::io::{self, Read, Write};
+
+/// Codeword of a positive integer, least significant Fibonacci first.
+pub fn encode(n: &BigInt) -> Result<Vec<bool>, String> {
+    if !n.is_positive() {
+        return Err(format!(
+            "Only positive integers have a Fibonacci code, got {}",
+            n
+        ));
+    }
+
+    // F(2), F(3), ... up to the largest one not above n
+    let mut fibs = vec![BigInt::one(), BigInt::from(2)];
+    while fibs.last().is_some_and(|last| last <= n) {
+        let next = &fibs[fibs.len() - 1] + &fibs[fibs.len() - 2];
+        fibs.push(next);
+    }
+    fibs.pop();
+
+    let mut bits = vec![false; fibs.len()];
+    let mut rest = n.clone();
+    for (i, fib) in fibs.iter().enumerate().rev() {
+        if fib <= &rest {
+            rest -= fib;
+            bits[i] = true;
+        }
+    }
+    bits.push(true);
+
+    Ok(bits)
+}
+
+/// Concatenated codewords of every value.
+pub fn encode_all<'a>(values: impl IntoIterator<Item = &'a BigInt>) -> Result<Vec<bool>, String> {
+    let mut bits = Vec::new();
+    for value in values {
+        bits.extend(encode(value)?);
+    }
+    Ok(bits)
+}
+
+/// Decodes a sequence of complete codewords.
+pub fn decode(bits: &[bool]) -> Result<Vec<BigInt>, String> {
+    let mut decoder = Decoder::default();
+    let mut values = Vec::new();
+
+    for &bit in bits {
+        if let Some(value) = decoder.push(bit) {
+            values.push(value);
+        }
+    }
+
+    if decoder.is_pending() {
+        return Err(String::from("Incomplete codeword at the end of input"));
+    }
+
+    Ok(values)
+}
+
+/// Packs codewords into bytes, most significant bit first, zero padded.
+pub fn encode_to_bytes<'a>(
+    values: impl IntoIterator<Item = &'a BigInt>,
+) -> Result<Vec<u8>, String> {
+    let mut writer = FiboWriter::new(Vec::new());
+    for value in values {
+        writer.write(value).map_err(|e| e.to_string())?;
+    }
+    writer.finish().map_err(|e| e.to_string())
+}
+
+pub fn decode_bytes(bytes: &[u8]) -> Result<Vec<BigInt>, String> {
+    FiboReader::new(bytes)
+        .collect::<io::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())
+}
+
+/// Incremental decoder state for one codeword.
+#[derive(Default)]
+struct Decoder {
+    value: BigInt,
+    /// (F(i+2), F(i+3)) for the next bit position i
+    fibs: Option<(BigInt, BigInt)>,
+    last_bit: bool,
+    any_bit: bool,
+}
+
+impl Decoder {
+    /// Returns the value once a codeword is complete.
+    fn push(&mut self, bit: bool) -> Option<BigInt> {
+        if bit && self.last_bit {
+            let value = std::mem::take(&mut self.value);
+            *self = Decoder::default();
+            return Some(value);
+        }
+
+        let (fib, next) = self
+            .fibs
+            .take()
+            .unwrap_or_else(|| (BigInt::one(), BigInt::from(2)));
+        if bit {
+            self.value += &fib;
+        }
+        self.fibs = Some((next.clone(), fib + next));
+        self.last_bit = bit;
+        self.any_bit |= bit;
+
+        None
+    }
+
+    /// Whether a codeword has started. Leading zero bits alone are padding.
+    fn is_pending(&self) -> bool {
+        self.any_bit
+    }
+}
+
+/// Streams codewords into a byte sink.
+pub struct FiboWriter<W: Write> {
+    inner: W,
+    byte: u8,
+    filled: u32,
+}
+
+impl<W: Write> FiboWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            byte: 0,
+            filled: 0,
+        }
+    }
+
+    pub fn write(&mut self, n: &BigInt) -> io::Result<()> {
+        let bits = encode(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        for bit in bits {
+            self.write_bit(bit)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the last partial byte with zero padding and returns the sink.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.filled > 0 {
+            self.byte <<= 8 - self.filled;
+            self.inner.write_all(&[self.byte])?;
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_bit(&mut self, bit: bool) -> io::Result<()> {
+        self.byte = (self.byte << 1) | bit as u8;
+        self.filled += 1;
+        if self.filled == 8 {
+            self.inner.write_all(&[self.byte])?;
+            self.byte = 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Reads codewords back from a byte source written by [`FiboWriter`].
+pub struct FiboReader<R: Read> {
+    inner: R,
+    byte: u8,
+    remaining: u32,
+    decoder: Decoder,
+}
+
+impl<R: Read> FiboReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            byte: 0,
+            remaining: 0,
+            decoder: Decoder::default(),
+        }
+    }
+
+    /// Next value, or `None` once only padding is left.
+    pub fn read(&mut self) -> io::Result<Option<BigInt>> {
+        loop {
+            if self.remaining == 0 {
+                let mut buf = [0u8; 1];
+                if self.inner.read(&mut buf)? == 0 {
+                    if self.decoder.is_pending() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::UnexpectedEof,
+                            "Incomplete codeword at the end of input",
+                        ));
+                    }
+                    return Ok(None);
+                }
+                self.byte = buf[0];
+                self.remaining = 8;
+            }
+
+            self.remaining -= 1;
+            let bit = (self.byte >> self.remaining) & 1 == 1;
+            if let Some(value) = self.decoder.push(bit) {
+                return Ok(Some(value));
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for FiboReader<R> {
+    type Item = io::Result<BigInt>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+    use test_case::test_case;
+
+    fn bits(code: &str) -> Vec<bool> {
+        code.chars().map(|c| c == '1').collect()
+    }
+
+    /// Deterministic xorshift values, so failures are reproducible.
+    fn sample_values(count: usize) -> Vec<BigInt> {
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        (0..count)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let value: BigInt = BigInt::from(state >> (i % 64)) + 1;
+                if i % 10 == 0 { value.pow(5) } else { value }
+            })
+            .collect()
+    }
+
+    #[test_case(1, "11"; "one")]
+    #[test_case(2, "011"; "two")]
+    #[test_case(3, "0011"; "three")]
+    #[test_case(4, "1011"; "four")]
+    #[test_case(11, "001011"; "eleven")]
+    #[test_case(65, "0100100011"; "sixty five")]
+    fn test_known_codes(n: i64, code: &str) {
+        assert_eq!(encode(&BigInt::from(n)), Ok(bits(code)));
+        assert_eq!(decode(&bits(code)), Ok(vec![BigInt::from(n)]));
+    }
+
+    #[test_case(0; "zero")]
+    #[test_case(-7; "negative")]
+    fn test_non_positive(n: i64) {
+        assert!(encode(&BigInt::from(n)).is_err());
+    }
+
+    #[test]
+    fn test_codewords_end_in_single_pair() {
+        for value in sample_values(500) {
+            let code = encode(&value).unwrap();
+            let pairs = code.windows(2).filter(|w| w[0] && w[1]).count();
+            assert_eq!(pairs, 1);
+            assert!(code.ends_with(&[true, true]));
+        }
+    }
+
+    #[test]
+    fn test_bits_round_trip() {
+        let values = sample_values(1_000);
+        let code = encode_all(&values).unwrap();
+        assert_eq!(decode(&code), Ok(values));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        for count in 0..40 {
+            let values = sample_values(count);
+            let bytes = encode_to_bytes(&values).unwrap();
+            assert_eq!(decode_bytes(&bytes), Ok(values));
+        }
+    }
+
+    #[test]
+    fn test_streaming_reader_writer() {
+        let values = sample_values(300);
+        let mut writer = FiboWriter::new(Vec::new());
+        for value in &values {
+            writer.write(value).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+
+        let mut reader = FiboReader::new(bytes.as_slice());
+        for value in &values {
+            assert_eq!(reader.read().unwrap().as_ref(), Some(value));
+        }
+        assert!(reader.read().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        assert!(decode(&bits("0101")).is_err());
+        assert_eq!(decode(&bits("1100")), Ok(vec![BigInt::one()]));
+
+        // 1 + F(30): the first byte holds only the leading 1 of a 30 bit codeword
+        let bytes = encode_to_bytes(&[BigInt::from(832_041)]).unwrap();
+        let error = decode_bytes(&bytes[..1]).unwrap_err();
+        assert_eq!(error, "Incomplete codeword at the end of input");
+    }
+
+    #[test]
+    fn test_writer_rejects_zero() {
+        let mut writer = FiboWriter::new(Vec::new());
+        let error = writer.write(&BigInt::zero()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::implementation::matmul::nth_term;
+use num_bigint::{BigInt, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Indices up to this bound are answered from the exact value. Above it the
+/// ψⁿ part of Binet's formula is below 10^-4000 relative and is ignored.
+const EXACT_INDEX_LIMIT: usize = 20_000;
+const GUARD_BITS: u64 = 64;
+const MAX_REFINEMENTS: u32 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitSummary {
+    pub index: BigInt,
+    pub negative: bool,
+    pub digit_count: BigInt,
+    pub leading: String,
+    pub trailing: String,
+}
+
+impl DigitSummary {
+    pub fn new(start_nums: &(BigInt, BigInt), n: &BigInt, k: usize) -> Result<Self, String> {
+        check_index(n)?;
+        if let Some(value) = exact_value(start_nums, n) {
+            let digits = value.magnitude().to_string();
+            return Ok(Self {
+                index: n.clone(),
+                negative: value.is_negative(),
+                digit_count: BigInt::from(digits.len()),
+                leading: digits.chars().take(k).collect(),
+                trailing: digits[digits.len().saturating_sub(k)..].to_string(),
+            });
+        }
+
+        let (digit_count, leading) = approximate(start_nums, n, k)?;
+        let trailing_len = digit_count.to_usize().map_or(k, |count| count.min(k));
+
+        Ok(Self {
+            index: n.clone(),
+            negative: eventual_sign(start_nums) == Sign::Minus,
+            trailing: trailing_digits(start_nums, n, trailing_len)?,
+            digit_count,
+            leading,
+        })
+    }
+}
+
+pub fn digit_count(start_nums: &(BigInt, BigInt), n: &BigInt) -> Result<BigInt, String> {
+    check_index(n)?;
+    match exact_value(start_nums, n) {
+        Some(value) => Ok(BigInt::from(value.magnitude().to_string().len())),
+        None => Ok(approximate(start_nums, n, 1)?.0),
+    }
+}
+
+pub fn leading_digits(
+    start_nums: &(BigInt, BigInt), n: &BigInt, k: usize,
+) -> Result<String, String> {
+    check_index(n)?;
+    match exact_value(start_nums, n) {
+        Some(value) => Ok(value.magnitude().to_string().chars().take(k).collect()),
+        None => Ok(approximate(start_nums, n, k)?.1),
+    }
+}
+
+/// Last `k` digits of |G(n)|, zero padded to `k`.
+pub fn trailing_digits(
+    start_nums: &(BigInt, BigInt), n: &BigInt, k: usize,
+) -> Result<String, String> {
+    let modulus = BigInt::from(10).pow(k as u32);
+    let mut rest = nth_term_mod(start_nums, n, &modulus)?;
+
+    let negative = match exact_value(start_nums, n) {
+        Some(value) => value.is_negative(),
+        None => eventual_sign(start_nums) == Sign::Minus,
+    };
+    if negative {
+        rest = (&modulus - rest).mod_floor(&modulus);
+    }
+
+    Ok(format!("{:0>width$}", rest.to_string(), width = k))
+}
+
+/// G(n) mod `modulus` by fast doubling, for any non-negative index.
+pub fn nth_term_mod(
+    start_nums: &(BigInt, BigInt), n: &BigInt, modulus: &BigInt,
+) -> Result<BigInt, String> {
+    check_index(n)?;
+    Ok(term_mod(start_nums, n, modulus))
+}
+
+/// G(n) = a·F(n−1) + b·F(n) mod `modulus`, for any index.
+pub(crate) fn term_mod(start_nums: &(BigInt, BigInt), n: &BigInt, modulus: &BigInt) -> BigInt {
+    let (f_n, f_next) = fibo_pair_mod(n, modulus);
+    let f_prev = (&f_next - &f_n).mod_floor(modulus);
+    (&start_nums.0 * f_prev + &start_nums.1 * f_n).mod_floor(modulus)
+}
+
+/// Negative indices are not supported.
+fn check_index(n: &BigInt) -> Result<(), String> {
+    if n.is_negative() {
+        return Err(format!("Index must be >= 0, got {}", n));
+    }
+    Ok(())
+}
+
+/// (F(n), F(n+1)) mod `modulus`, negative n by F(−m) = (−1)^(m+1)·F(m).
+fn fibo_pair_mod(n: &BigInt, modulus: &BigInt) -> (BigInt, BigInt) {
+    if n.is_negative() {
+        let m = -n;
+        let (f_prev, f_m) = fibo_pair_mod(&(&m - 1), modulus);
+        return if m.bit(0) {
+            (f_m, (-f_prev).mod_floor(modulus))
+        } else {
+            ((-f_m).mod_floor(modulus), f_prev)
+        };
+    }
+
+    let mut a = BigInt::zero();
+    let mut b = BigInt::one().mod_floor(modulus);
+
+    for bit in (0..n.bits()).rev() {
+        let twice_b: BigInt = &b << 1;
+        let doubled = (&a * (twice_b - &a)).mod_floor(modulus);
+        let doubled_next = (&a * &a + &b * &b).mod_floor(modulus);
+        (a, b) = if n.bit(bit) {
+            let sum = (&doubled + &doubled_next).mod_floor(modulus);
+            (doubled_next, sum)
+        } else {
+            (doubled, doubled_next)
+        };
+    }
+
+    (a, b)
+}
+
+fn exact_value(start_nums: &(BigInt, BigInt), n: &BigInt) -> Option<BigInt> {
+    let n = n.to_usize().filter(|&n| n <= EXACT_INDEX_LIMIT)?;
+    Some(nth_term(start_nums, n))
+}
+
+/// Sign of a + bφ, which is the sign of G(n) for every large n.
+fn eventual_sign((a, b): &(BigInt, BigInt)) -> Sign {
+    // a + bφ = (c + b√5) / 2 with c = 2a + b
+    let c: BigInt = (a << 1) + b;
+    match (c.sign(), b.sign()) {
+        (Sign::NoSign, sign) | (sign, Sign::NoSign) => sign,
+        (c_sign, b_sign) if c_sign == b_sign => c_sign,
+        (c_sign, b_sign) => {
+            if &c * &c > b * b * 5 {
+                c_sign
+            } else {
+                b_sign
+            }
+        }
+    }
+}
+
+/// Digit count and leading `k` digits from log10|G(n)| ≈
+/// (n−1)·log10 φ + log10|a + bφ| − log10 √5.
+fn approximate(
+    start_nums: &(BigInt, BigInt), n: &BigInt, k: usize,
+) -> Result<(BigInt, String), String> {
+    if eventual_sign(start_nums) == Sign::NoSign {
+        return Ok((BigInt::one(), String::from("0")));
+    }
+
+    let seed_bits = start_nums.0.bits().max(start_nums.1.bits());
+    let mut precision = GUARD_BITS * 2 + n.bits() + seed_bits + (k as u64 + 1) * 4;
+
+    for _ in 0..MAX_REFINEMENTS {
+        if let Some(result) = approximate_with_precision(start_nums, n, k, precision) {
+            return Ok(result);
+        }
+        precision *= 2;
+    }
+
+    approximate_with_precision(start_nums, n, k, precision).ok_or_else(|| {
+        format!(
+            "Cannot determine {} leading digits of term {} at {} bits of precision",
+            k, n, precision
+        )
+    })
+}
+
+/// Returns `None` when the result lies too close to a digit boundary to be
+/// trusted at this precision.
+fn approximate_with_precision(
+    start_nums: &(BigInt, BigInt), n: &BigInt, k: usize, precision: u64,
+) -> Option<(BigInt, String)> {
+    let fixed = Fixed::new(precision);
+    let margin = BigInt::one() << (n.bits() + GUARD_BITS / 2);
+
+    let sqrt5 = fixed.sqrt(&(fixed.integer(&BigInt::from(5))));
+    let phi = (fixed.one() + &sqrt5) >> 1;
+    let seed: BigInt = fixed.integer(&start_nums.0) + &start_nums.1 * &phi;
+    let seed = seed.abs();
+
+    let ln10 = fixed.ln(&fixed.integer(&BigInt::from(10)));
+    let ln_value = (n - 1) * fixed.ln(&phi) + fixed.ln(&seed) - fixed.ln(&sqrt5);
+    let log10 = fixed.div(&ln_value, &ln10);
+
+    let (integer, fraction) = log10.div_mod_floor(&fixed.one());
+    if fraction < margin || fixed.one() - &fraction < margin {
+        return None;
+    }
+
+    let mantissa = fixed.exp(&fixed.mul(&fraction, &ln10));
+    let scaled = mantissa * BigInt::from(10).pow(k.saturating_sub(1) as u32);
+    let (leading, rest) = scaled.div_mod_floor(&fixed.one());
+    let margin = margin * BigInt::from(10).pow(k as u32 + 1);
+    if rest < margin || fixed.one() - &rest < margin {
+        return None;
+    }
+
+    Some((integer + 1, leading.to_string()))
+}
+
+/// Binary fixed-point arithmetic: `x` stands for x / 2^precision.
+struct Fixed {
+    precision: u64,
+}
+
+impl Fixed {
+    fn new(precision: u64) -> Self {
+        Self { precision }
+    }
+
+    fn one(&self) -> BigInt {
+        BigInt::one() << self.precision
+    }
+
+    fn integer(&self, value: &BigInt) -> BigInt {
+        value << self.precision
+    }
+
+    fn mul(&self, x: &BigInt, y: &BigInt) -> BigInt {
+        (x * y) >> self.precision
+    }
+
+    fn div(&self, x: &BigInt, y: &BigInt) -> BigInt {
+        (x << self.precision) / y
+    }
+
+    fn sqrt(&self, x: &BigInt) -> BigInt {
+        (x << self.precision).sqrt()
+    }
+
+    /// 2·atanh(t) = ln((1 + t) / (1 − t)) for |t| < 1
+    fn atanh_twice(&self, t: &BigInt) -> BigInt {
+        let t_squared = self.mul(t, t);
+        let mut power = t.clone();
+        let mut sum = BigInt::zero();
+        let mut divisor = 1u64;
+
+        while !power.is_zero() {
+            sum += &power / divisor;
+            power = self.mul(&power, &t_squared);
+            divisor += 2;
+        }
+
+        sum << 1
+    }
+
+    /// Natural logarithm of a positive value, reduced to a mantissa in [1, 2).
+    fn ln(&self, x: &BigInt) -> BigInt {
+        let exponent = x.bits() as i64 - self.precision as i64 - 1;
+        let mantissa = if exponent >= 0 {
+            x >> exponent as u64
+        } else {
+            x << (-exponent) as u64
+        };
+
+        let one = self.one();
+        let ln_mantissa = self.atanh_twice(&self.div(&(&mantissa - &one), &(&mantissa + &one)));
+        let ln2 = self.atanh_twice(&(one / 3));
+
+        ln2 * exponent + ln_mantissa
+    }
+
+    /// eˣ for small non-negative x, halving the argument before the series.
+    fn exp(&self, x: &BigInt) -> BigInt {
+        const HALVINGS: u64 = 16;
+
+        // x / 2^HALVINGS at the extended precision is x << HALVINGS
+        let extended = Fixed::new(self.precision + HALVINGS * 2);
+        let reduced = x << HALVINGS;
+
+        let mut term = extended.one();
+        let mut sum = BigInt::zero();
+        let mut k = 1u64;
+        while !term.is_zero() {
+            sum += &term;
+            term = extended.mul(&term, &reduced) / k;
+            k += 1;
+        }
+
+        for _ in 0..HALVINGS {
+            sum = extended.mul(&sum, &sum);
+        }
+
+        sum >> (HALVINGS * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    #[test_case(seeds(0, 1), 25_000; "classic")]
+    #[test_case(seeds(2, 1), 21_111; "lucas")]
+    #[test_case(seeds(-5, 8), 30_000; "mixed signs")]
+    #[test_case(seeds(13, -8), 24_000; "eventually negative")]
+    fn test_approximation_matches_exact(start_nums: (BigInt, BigInt), n: usize) {
+        let exact = nth_term(&start_nums, n);
+        let digits = exact.magnitude().to_string();
+        let n = BigInt::from(n);
+
+        let (count, leading) = approximate(&start_nums, &n, 30).unwrap();
+        assert_eq!(count, BigInt::from(digits.len()));
+        assert_eq!(leading, digits[..30]);
+        assert_eq!(
+            trailing_digits(&start_nums, &n, 12).unwrap(),
+            digits[digits.len() - 12..]
+        );
+        assert_eq!(eventual_sign(&start_nums), exact.sign());
+    }
+
+    #[test]
+    fn test_small_index_is_exact() {
+        let summary = DigitSummary::new(&seeds(0, 1), &BigInt::from(30), 3).unwrap();
+        assert_eq!(summary.digit_count, BigInt::from(6)); // 832040
+        assert_eq!(summary.leading, "832");
+        assert_eq!(summary.trailing, "040");
+        assert!(!summary.negative);
+    }
+
+    #[test]
+    fn test_astronomical_index() {
+        let n = BigInt::from(10u64.pow(18));
+        let summary = DigitSummary::new(&seeds(0, 1), &n, 7).unwrap();
+        assert_eq!(
+            summary.digit_count,
+            BigInt::from(208_987_640_249_978_734u64)
+        );
+        assert_eq!(summary.leading, "2628978");
+        assert_eq!(summary.trailing, "0546875");
+    }
+
+    #[test_case(0, 0; "zero")]
+    #[test_case(10, 55; "ten")]
+    #[test_case(100, 915_075; "hundred")]
+    fn test_nth_term_mod(n: u64, expected: i64) {
+        let modulus = BigInt::from(1_000_000);
+        let value = nth_term_mod(&seeds(0, 1), &BigInt::from(n), &modulus);
+        assert_eq!(value, Ok(BigInt::from(expected)));
+    }
+
+    #[test_case(-1, (1, 0); "minus one")]
+    #[test_case(-6, (89, 5); "minus six")]
+    #[test_case(10, (55, 89); "ten")]
+    fn test_fibo_pair_mod(n: i64, (f_n, f_next): (u64, u64)) {
+        let pair = fibo_pair_mod(&BigInt::from(n), &BigInt::from(97));
+        assert_eq!(pair, (BigInt::from(f_n), BigInt::from(f_next)));
+    }
+
+    #[test]
+    fn test_zero_sequence() {
+        let summary = DigitSummary::new(&seeds(0, 0), &BigInt::from(10u64.pow(12)), 5).unwrap();
+        assert_eq!(summary.digit_count, BigInt::one());
+        assert_eq!(summary.leading, "0");
+    }
+
+    #[test]
+    fn test_negative_index_is_rejected() {
+        let n = BigInt::from(-5);
+        let error = String::from("Index must be >= 0, got -5");
+        assert_eq!(DigitSummary::new(&seeds(0, 1), &n, 3), Err(error.clone()));
+        assert_eq!(digit_count(&seeds(0, 1), &n), Err(error.clone()));
+        assert_eq!(leading_digits(&seeds(0, 1), &n, 3), Err(error.clone()));
+        assert_eq!(trailing_digits(&seeds(0, 1), &n, 3), Err(error.clone()));
+        let modulus = BigInt::from(10);
+        assert_eq!(nth_term_mod(&seeds(0, 1), &n, &modulus), Err(error));
+    }
+}
@@ -0,0 +1,409 @@
+use crate::radix::{decimal_string, leading_in_base, trailing_in_base};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::Zero;
+
+const ELLIPSIS: char = '…';
+
+/// How a term is written: base 2–36 positional digits or scientific
+/// notation, optional digit grouping and an optional width beyond which the
+/// middle of the digits is replaced by "…". Wide values only convert the
+/// digits that are shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    base: u32,
+    notation: Notation,
+    grouping: Option<Grouping>,
+    max_width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Positional,
+    /// d.ddd…eN in base 10, d.ddd…×b^N otherwise, rounded half up
+    Scientific {
+        significant: usize,
+    },
+}
+
+/// Separator between groups of `size` digits counted from the right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grouping {
+    pub separator: char,
+    pub size: usize,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            base: 10,
+            notation: Notation::Positional,
+            grouping: None,
+            max_width: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    pub fn grouping(&self) -> Option<Grouping> {
+        self.grouping
+    }
+
+    pub fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    pub fn set_base(&mut self, base: u32) -> Result<&mut Self, String> {
+        if !(2..=36).contains(&base) {
+            return Err(format!("Base must be in 2..=36, got {}", base));
+        }
+        self.base = base;
+        Ok(self)
+    }
+
+    pub fn set_notation(&mut self, notation: Notation) -> Result<&mut Self, String> {
+        if notation == (Notation::Scientific { significant: 0 }) {
+            return Err(String::from("Scientific notation needs at least one digit"));
+        }
+        self.notation = notation;
+        Ok(self)
+    }
+
+    /// Only applies to positional notation.
+    pub fn set_grouping(&mut self, grouping: Option<Grouping>) -> Result<&mut Self, String> {
+        if grouping.is_some_and(|grouping| grouping.size == 0) {
+            return Err(String::from("Digit groups must not be empty"));
+        }
+        self.grouping = grouping;
+        Ok(self)
+    }
+
+    /// Characters including sign and prefix; at least 3 so "…" has digits
+    /// on both sides.
+    pub fn set_max_width(&mut self, max_width: Option<usize>) -> Result<&mut Self, String> {
+        if max_width.is_some_and(|width| width < 3) {
+            return Err(String::from("Width must be at least 3"));
+        }
+        self.max_width = max_width;
+        Ok(self)
+    }
+
+    pub fn format(&self, value: &BigInt) -> String {
+        let sign = if value.sign() == Sign::Minus { "-" } else { "" };
+        let text = match self.notation {
+            Notation::Positional => self.positional(sign, value.magnitude()),
+            Notation::Scientific { significant } => {
+                format!(
+                    "{}{}",
+                    sign,
+                    self.scientific(value.magnitude(), significant)
+                )
+            }
+        };
+
+        match self.max_width {
+            Some(width) => truncate_middle(&text, width),
+            None => text,
+        }
+    }
+
+    fn positional(&self, sign: &str, magnitude: &BigUint) -> String {
+        let prefix = format!("{}{}", sign, base_prefix(self.base));
+        let Some(width) = self.max_width else {
+            return prefix + &self.group(&self.all_digits(magnitude), 0);
+        };
+
+        let (_, count) = leading_in_base(magnitude, self.base, 0);
+        let shown = |digits: u64| match self.grouping {
+            Some(grouping) => digits + (digits - 1) / grouping.size as u64,
+            None => digits,
+        };
+        let prefix_len = prefix.chars().count();
+        if prefix_len as u64 + shown(count) <= width as u64 {
+            return prefix + &self.group(&self.all_digits(magnitude), 0);
+        }
+
+        // Both sides come out of groups of that many digits or more
+        let budget = width.saturating_sub(prefix_len + 1).max(2);
+        let (head_len, tail_len) = (budget.div_ceil(2), budget / 2);
+        let (head, _) = leading_in_base(magnitude, self.base, head_len);
+        let tail = trailing_in_base(magnitude, self.base, tail_len);
+
+        let head: String = self
+            .group(&head, count - head.len() as u64)
+            .chars()
+            .take(head_len)
+            .collect();
+        let tail = self.group(&tail, 0);
+        let tail: String = tail.chars().skip(tail.chars().count() - tail_len).collect();
+        format!("{}{}{}{}", prefix, head, ELLIPSIS, tail)
+    }
+
+    fn scientific(&self, magnitude: &BigUint, significant: usize) -> String {
+        if magnitude.is_zero() {
+            return String::from("0");
+        }
+
+        let (_, count) = leading_in_base(magnitude, self.base, 0);
+        let mut exponent = count - 1;
+        let dropped = count.saturating_sub(significant as u64);
+        let unit = BigUint::from(self.base).pow(dropped as u32);
+        let (mut kept, rest) = magnitude.div_rem(&unit);
+        // Half up on the exact remainder; odd bases have no half digit
+        if rest * 2u32 >= unit {
+            kept += 1u32;
+        }
+
+        let mut digits: Vec<u32> = kept
+            .to_str_radix(self.base)
+            .chars()
+            .filter_map(|digit| digit.to_digit(self.base))
+            .collect();
+        if digits.len() as u64 > count - dropped {
+            // Every digit carried over, as in 9.99 → 10.0
+            digits.truncate(significant);
+            exponent += 1;
+        }
+
+        let mut mantissa: String = digits
+            .iter()
+            .filter_map(|&digit| char::from_digit(digit, self.base))
+            .collect();
+        if mantissa.len() > 1 {
+            mantissa.insert(1, '.');
+        }
+        match self.base {
+            10 => format!("{}e{}", mantissa, exponent),
+            base => format!("{}×{}^{}", mantissa, base, exponent),
+        }
+    }
+
+    fn all_digits(&self, magnitude: &BigUint) -> String {
+        match self.base {
+            10 => decimal_string(&BigInt::from(magnitude.clone())),
+            base => magnitude.to_str_radix(base),
+        }
+    }
+
+    /// `digits` with separators, `digits_after` being how many digits of the
+    /// whole number follow them.
+    fn group(&self, digits: &str, digits_after: u64) -> String {
+        let Some(grouping) = self.grouping else {
+            return digits.to_string();
+        };
+
+        let size = grouping.size as u64;
+        let len = digits.len() as u64;
+        let mut grouped = String::with_capacity(digits.len() * 2);
+        for (i, digit) in digits.chars().enumerate() {
+            grouped.push(digit);
+            let right = digits_after + len - 1 - i as u64;
+            if right > 0 && right.is_multiple_of(size) {
+                grouped.push(grouping.separator);
+            }
+        }
+        grouped
+    }
+}
+
+impl Grouping {
+    pub fn new(separator: char, size: usize) -> Self {
+        Self { separator, size }
+    }
+
+    /// Thousands separator of a locale such as "de_DE.UTF-8" or "fr-CA",
+    /// "," when it is not known.
+    pub fn for_locale(locale: &str, size: usize) -> Self {
+        let name = locale.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = name.split(['_', '-']);
+        let language = parts.next().unwrap_or_default().to_lowercase();
+        let country = parts.next().unwrap_or_default().to_uppercase();
+
+        let separator = match (language.as_str(), country.as_str()) {
+            (_, "CH" | "LI") => '\'',
+            ("de" | "it" | "es" | "pt" | "nl" | "da" | "id" | "tr" | "el" | "ro", _) => '.',
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "uk" | "hu" | "bg"
+                | "et" | "lt" | "lv",
+                _,
+            ) => '\u{202F}',
+            _ => ',',
+        };
+        Self::new(separator, size)
+    }
+
+    /// `for_locale` of LC_ALL, LC_NUMERIC or LANG, whichever is set first.
+    pub fn from_env(size: usize) -> Self {
+        let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        Self::for_locale(&locale, size)
+    }
+}
+
+/// `text` cut to `width` characters by replacing its middle with "…".
+pub fn truncate_middle(text: &str, width: usize) -> String {
+    let len = text.chars().count();
+    if len <= width {
+        return text.to_string();
+    }
+
+    let kept = width.saturating_sub(1);
+    let (head, tail) = (kept.div_ceil(2), kept / 2);
+    let mut result: String = text.chars().take(head).collect();
+    result.push(ELLIPSIS);
+    result.extend(text.chars().skip(len - tail));
+    result
+}
+
+fn base_prefix(base: u32) -> &'static str {
+    match base {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::doubling::fibo_pair;
+    use test_case::test_case;
+
+    fn format(
+        value: &BigInt, base: u32, grouping: Option<Grouping>, width: Option<usize>,
+    ) -> String {
+        let mut number_format = NumberFormat::default();
+        number_format.set_base(base).unwrap();
+        number_format.set_grouping(grouping).unwrap();
+        number_format.set_max_width(width).unwrap();
+        number_format.format(value)
+    }
+
+    fn scientific(value: &BigInt, base: u32, significant: usize) -> String {
+        let mut number_format = NumberFormat::default();
+        number_format.set_base(base).unwrap();
+        number_format
+            .set_notation(Notation::Scientific { significant })
+            .unwrap();
+        number_format.format(value)
+    }
+
+    #[test_case(832_040, 10 => "832040"; "decimal")]
+    #[test_case(-832_040, 16 => "-0xcb228"; "hex")]
+    #[test_case(21, 2 => "0b10101"; "binary")]
+    #[test_case(1_295, 36 => "zz"; "base 36")]
+    #[test_case(0, 8 => "0o0"; "zero")]
+    fn test_bases(value: i64, base: u32) -> String {
+        format(&BigInt::from(value), base, None, None)
+    }
+
+    #[test_case(1_234_567, Grouping::new(',', 3) => "1,234,567"; "thousands")]
+    #[test_case(-123_456, Grouping::new('.', 3) => "-123.456"; "full groups")]
+    #[test_case(999, Grouping::new(',', 3) => "999"; "one group")]
+    fn test_grouping(value: i64, grouping: Grouping) -> String {
+        format(&BigInt::from(value), 10, Some(grouping), None)
+    }
+
+    #[test]
+    fn test_grouping_in_binary() {
+        let grouping = Grouping::new('_', 4);
+        assert_eq!(
+            format(&BigInt::from(0b1_0110_1001), 2, Some(grouping), None),
+            "0b1_0110_1001"
+        );
+    }
+
+    #[test_case(832_040, 10, 3 => "8.32e5"; "rounds down")]
+    #[test_case(-832_040, 10, 2 => "-8.3e5"; "negative")]
+    #[test_case(999_500, 10, 3 => "1.00e6"; "carries into the exponent")]
+    #[test_case(7, 10, 4 => "7e0"; "fewer digits than asked")]
+    #[test_case(0, 10, 3 => "0"; "zero")]
+    #[test_case(255, 16, 1 => "1×16^2"; "hex rounds up")]
+    #[test_case(12_345, 10, 1 => "1e4"; "one digit")]
+    #[test_case(14, 3, 1 => "2×3^2"; "odd base rounds on the remainder")]
+    #[test_case(13, 3, 1 => "1×3^2"; "odd base below half")]
+    #[test_case(26, 3, 2 => "1.0×3^3"; "odd base carries")]
+    fn test_scientific(value: i64, base: u32, significant: usize) -> String {
+        scientific(&BigInt::from(value), base, significant)
+    }
+
+    #[test]
+    fn test_scientific_of_large_value() {
+        let value = fibo_pair(&BigInt::from(10_000)).0;
+        let digits = value.to_string();
+        assert!(digits.starts_with("336447648") && digits.len() == 2_090);
+        assert_eq!(scientific(&value, 10, 8), "3.3644765e2089");
+        assert_eq!(scientific(&value, 10, 9), "3.36447649e2089");
+    }
+
+    #[test_case(None, 20 => "1234567890…456789012"; "plain")]
+    #[test_case(Some(Grouping::new(',', 3)), 20 => "123,456,78…6,789,012"; "grouped")]
+    #[test_case(None, 3 => "1…2"; "narrowest")]
+    fn test_middle_ellipsis(grouping: Option<Grouping>, width: usize) -> String {
+        let value: BigInt = "123456789012345678901234567890123456789012"
+            .parse()
+            .unwrap();
+        let text = format(&value, 10, grouping, Some(width));
+        assert!(text.chars().count() <= width);
+        text
+    }
+
+    #[test]
+    fn test_middle_ellipsis_matches_full_conversion() {
+        let value = -fibo_pair(&BigInt::from(30_000)).0;
+        for (base, grouping) in [(10, Some(Grouping::new(' ', 3))), (16, None), (2, None)] {
+            let full = format(&value, base, grouping, None);
+            let cut = format(&value, base, grouping, Some(41));
+            let (head, tail) = cut.split_once(ELLIPSIS).unwrap();
+            assert_eq!(cut.chars().count(), 41);
+            assert!(full.starts_with(head) && full.ends_with(tail), "{}", cut);
+        }
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let mut number_format = NumberFormat::default();
+        assert!(number_format.set_base(1).is_err());
+        assert!(number_format.set_base(37).is_err());
+        assert!(
+            number_format
+                .set_notation(Notation::Scientific { significant: 0 })
+                .is_err()
+        );
+        assert!(
+            number_format
+                .set_grouping(Some(Grouping::new(',', 0)))
+                .is_err()
+        );
+        assert!(number_format.set_max_width(Some(2)).is_err());
+        assert_eq!(number_format, NumberFormat::default());
+    }
+
+    #[test_case("de_DE.UTF-8" => '.'; "german")]
+    #[test_case("de_CH.UTF-8" => '\''; "swiss german")]
+    #[test_case("fr-CA" => '\u{202F}'; "french")]
+    #[test_case("en_US.UTF-8" => ','; "english")]
+    #[test_case("C" => ','; "posix")]
+    #[test_case("" => ','; "unset")]
+    fn test_locale_separator(locale: &str) -> char {
+        Grouping::for_locale(locale, 3).separator
+    }
+
+    #[test_case("abcdef", 6 => "abcdef"; "fits")]
+    #[test_case("abcdefgh", 6 => "abc…gh"; "cut")]
+    fn test_truncate_middle(text: &str, width: usize) -> String {
+        truncate_middle(text, width)
+    }
+}
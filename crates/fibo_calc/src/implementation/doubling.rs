@@ -0,0 +1,82 @@
+use crate::implementation::parallel_mul::{mul, square};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+/// (F(n), F(n+1)) of the classic sequence by fast doubling. Negative indices
+/// follow F(−n) = (−1)^(n+1)·F(n).
+pub fn fibo_pair(n: &BigInt) -> (BigInt, BigInt) {
+    if n.is_negative() {
+        let m = -n;
+        let (f_prev, f_m) = fibo_pair(&(&m - 1));
+        return if m.bit(0) {
+            (f_m, -f_prev)
+        } else {
+            (-f_m, f_prev)
+        };
+    }
+
+    continue_fibo_pair(n, n.bits(), (BigInt::zero(), BigInt::one()), |_, _| {})
+}
+
+/// Fast doubling for n ≥ 0 resumed from `pair`, the result for the bits of n
+/// above the lowest `bits_left`. `step` gets the bits still left and the pair
+/// after each bit.
+pub fn continue_fibo_pair(
+    n: &BigInt, bits_left: u64, pair: (BigInt, BigInt),
+    mut step: impl FnMut(u64, &(BigInt, BigInt)),
+) -> (BigInt, BigInt) {
+    let mut pair = pair;
+
+    for bit in (0..bits_left).rev() {
+        let (a, b) = &pair;
+        let twice_b: BigInt = b << 1;
+        let doubled = mul(a, &(twice_b - a));
+        let doubled_next = square(a) + square(b);
+        pair = if n.bit(bit) {
+            let sum = &doubled + &doubled_next;
+            (doubled_next, sum)
+        } else {
+            (doubled, doubled_next)
+        };
+        step(bit, &pair);
+    }
+
+    pair
+}
+
+/// Moves the pair (F(m), F(m+1)) forward to (F(m+g), F(m+g+1)) given
+/// (F(g), F(g+1)).
+pub fn jump_pair(pair: &(BigInt, BigInt), gap_pair: &(BigInt, BigInt)) -> (BigInt, BigInt) {
+    let (f_m, f_m_next) = pair;
+    let (f_g, f_g_next) = gap_pair;
+    let f_g_prev = f_g_next - f_g;
+
+    (
+        mul(f_m_next, f_g) + mul(f_m, &f_g_prev),
+        mul(f_m_next, f_g_next) + mul(f_m, f_g),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, (0, 1); "zero")]
+    #[test_case(1, (1, 1); "one")]
+    #[test_case(10, (55, 89); "ten")]
+    #[test_case(-1, (1, 0); "minus one")]
+    #[test_case(-6, (-8, 5); "minus six")]
+    #[test_case(-7, (13, -8); "minus seven")]
+    fn test_fibo_pair(n: i64, expected: (i64, i64)) {
+        let expected = (BigInt::from(expected.0), BigInt::from(expected.1));
+        assert_eq!(fibo_pair(&BigInt::from(n)), expected);
+    }
+
+    #[test]
+    fn test_jump_pair() {
+        let from = fibo_pair(&BigInt::from(-5));
+        let gap = fibo_pair(&BigInt::from(37));
+        assert_eq!(jump_pair(&from, &gap), fibo_pair(&BigInt::from(32)));
+    }
+}
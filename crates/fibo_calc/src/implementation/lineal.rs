@@ -1,4 +1,5 @@
 use crate::calculator::ImplementationFibo;
+use crate::implementation::doubling::{fibo_pair, jump_pair};
 use num_bigint::BigInt;
 
 pub struct LinealFibo {
@@ -16,6 +17,13 @@ impl ImplementationFibo for LinealFibo {
         }
         Default::default()
     }
+
+    /// (G(k−2), G(k−1)) moves n − 2 terms ahead in one doubling jump.
+    fn jump_to(&mut self, n: usize) {
+        let gap = fibo_pair(&BigInt::from(n.saturating_sub(2)));
+        let pair = (self.pre_last.clone(), self.last.clone());
+        (self.pre_last, self.last) = jump_pair(&pair, &gap);
+    }
 }
 
 impl Default for LinealFibo {
@@ -30,11 +38,11 @@ impl Default for LinealFibo {
 impl Iterator for LinealFibo {
     type Item = BigInt;
 
+    /// Adds into `pre_last` and swaps, so the only allocation is the returned copy.
     fn next(&mut self) -> Option<Self::Item> {
-        let res = &self.last + &self.pre_last;
-        self.pre_last = self.last.clone();
-        self.last = res.clone();
-        Some(res)
+        self.pre_last += &self.last;
+        std::mem::swap(&mut self.pre_last, &mut self.last);
+        Some(self.last.clone())
     }
 }
 
@@ -81,6 +89,19 @@ mod tests {
         }
     }
 
+    #[test_case(2; "no jump")]
+    #[test_case(3; "one step")]
+    #[test_case(500; "far")]
+    fn test_jump_to_matches_stepping(n: usize) {
+        let start_nums = (BigInt::from(-4), BigInt::from(7));
+        let mut jumped = LinealFibo::new(Some(start_nums.clone()));
+        jumped.jump_to(n);
+
+        let stepped = LinealFibo::new(Some(start_nums)).skip(n - 2);
+        let jumped: Vec<BigInt> = jumped.take(5).collect();
+        assert_eq!(jumped, stepped.take(5).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_iterator_trait() {
         let fibo = LinealFibo::default();
@@ -1,4 +1,5 @@
 use crate::calculator::ImplementationFibo;
+use crate::implementation::parallel_mul;
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
 
@@ -15,29 +16,48 @@ impl M2x2 {
     fn new(n00: BigInt, n01_and_n10: BigInt, n11: BigInt) -> Self {
         Self { n00, n01_and_n10, n11 }
     }
+
+    /// a² + b², b·(a + c), b² + c²: four products instead of six, with the
+    /// sum a + c built in `scratch`.
+    fn square(&mut self, scratch: &mut BigInt) {
+        scratch.clone_from(&self.n00);
+        *scratch += &self.n11;
+
+        let b_squared = parallel_mul::square(&self.n01_and_n10);
+        self.n01_and_n10 = parallel_mul::mul(&self.n01_and_n10, scratch);
+        self.n00 = parallel_mul::square(&self.n00) + &b_squared;
+        self.n11 = parallel_mul::square(&self.n11) + b_squared;
+    }
 }
 
+/// Both sides are symmetric, so b·e serves the two diagonal entries: five
+/// products instead of six.
 impl Mul<&M2x2> for &M2x2 {
     type Output = M2x2;
 
     fn mul(self, other: &M2x2) -> M2x2 {
+        let shared = parallel_mul::mul(&self.n01_and_n10, &other.n01_and_n10);
         M2x2 {
-            n00: &self.n00 * &other.n00 + &self.n01_and_n10 * &other.n01_and_n10,
-            n01_and_n10: &self.n00 * &other.n01_and_n10 + &self.n01_and_n10 * &other.n11,
-            n11: &self.n01_and_n10 * &other.n01_and_n10 + &self.n11 * &other.n11,
+            n00: parallel_mul::mul(&self.n00, &other.n00) + &shared,
+            n01_and_n10: parallel_mul::mul(&self.n00, &other.n01_and_n10)
+                + parallel_mul::mul(&self.n01_and_n10, &other.n11),
+            n11: shared + parallel_mul::mul(&self.n11, &other.n11),
         }
     }
 }
 
+/// Same products as `Mul`, the sums accumulate into the existing entries.
 impl MulAssign<&M2x2> for M2x2 {
     fn mul_assign(&mut self, other: &M2x2) {
-        let n00 = &self.n00 * &other.n00 + &self.n01_and_n10 * &other.n01_and_n10;
-        let n01_and_n10 = &self.n00 * &other.n01_and_n10 + &self.n01_and_n10 * &other.n11;
-        let n11 = &self.n01_and_n10 * &other.n01_and_n10 + &self.n11 * &other.n11;
-        
-        self.n00 = n00;
+        let shared = parallel_mul::mul(&self.n01_and_n10, &other.n01_and_n10);
+        let mut n01_and_n10 = parallel_mul::mul(&self.n00, &other.n01_and_n10);
+        n01_and_n10 += parallel_mul::mul(&self.n01_and_n10, &other.n11);
+
+        self.n00 = parallel_mul::mul(&self.n00, &other.n00);
+        self.n00 += &shared;
+        self.n11 = parallel_mul::mul(&self.n11, &other.n11);
+        self.n11 += shared;
         self.n01_and_n10 = n01_and_n10;
-        self.n11 = n11;
     }
 }
 
@@ -45,6 +65,8 @@ pub struct MatmulFibo {
     fibo: M2x2,
     step: M2x2,
     count: usize,
+    /// Reused by `M2x2::square`
+    scratch: BigInt,
 }
 
 impl ImplementationFibo for MatmulFibo {
@@ -62,31 +84,46 @@ impl ImplementationFibo for MatmulFibo {
 
         Default::default()
     }
+
+    fn term(start_nums: (BigInt, BigInt), n: usize) -> BigInt {
+        nth_term(&start_nums, n)
+    }
 }
 
 impl MatmulFibo {
     pub fn calc_one(&mut self, n: BigInt) -> BigInt {
         let mut n = n - 2;
 
-        while &n > &BigInt::zero() {
+        while n > BigInt::zero() {
             if (&n & &BigInt::one()) != BigInt::zero() {
                 self.fibo *= &self.step;
             }
 
-            self.step = &self.step * &self.step;
             n >>= 1;
+            if n > BigInt::zero() {
+                self.step.square(&mut self.scratch);
+            }
         }
 
         self.fibo.n00.clone()
     }
 }
 
+/// Term `n` (0-based) of the sequence started by `start_nums`
+pub fn nth_term(start_nums: &(BigInt, BigInt), n: usize) -> BigInt {
+    if n == 0 {
+        return start_nums.0.clone();
+    }
+    MatmulFibo::new(Some(start_nums.clone())).calc_one(BigInt::from(n + 1))
+}
+
 impl Default for MatmulFibo {
     fn default() -> Self {
         Self {
             step: M2x2::new(BigInt::one(), BigInt::one(), BigInt::zero()),
             fibo: M2x2::new(BigInt::one(), BigInt::zero(), BigInt::one()),
             count: 1,
+            scratch: BigInt::zero(),
         }
     }
 }
@@ -178,6 +215,34 @@ mod tests {
         assert_eq!(result.n11, BigInt::from(expected_n11));
     }
 
+    #[test_case((1, 1, 0); "fibonacci step")]
+    #[test_case((5, 3, 2); "fibonacci power")]
+    #[test_case((7, -4, 11); "mixed signs")]
+    fn test_square_matches_mul(entries: (i32, i32, i32)) {
+        let matrix = M2x2::new(
+            BigInt::from(entries.0),
+            BigInt::from(entries.1),
+            BigInt::from(entries.2),
+        );
+        let expected = &matrix * &matrix;
+
+        let mut squared = M2x2::new(
+            BigInt::from(entries.0),
+            BigInt::from(entries.1),
+            BigInt::from(entries.2),
+        );
+        squared.square(&mut BigInt::from(99));
+        assert_eq!(squared, expected);
+
+        let mut assigned = M2x2::new(
+            matrix.n00.clone(),
+            matrix.n01_and_n10.clone(),
+            matrix.n11.clone(),
+        );
+        assigned *= &matrix;
+        assert_eq!(assigned, expected);
+    }
+
     #[test_case(&[3, 7, 10, 17, 27] ; "custom sequence starting with 3,7")]
     #[test_case(&[0, 1, 1, 2, 3, 5, 8, 13] ; "default fibonacci sequence")]
     fn test_sequence(expected: &[i32]) {
@@ -1,2 +1,4 @@
+pub mod doubling;
 pub mod lineal;
 pub mod matmul;
+pub mod parallel_mul;
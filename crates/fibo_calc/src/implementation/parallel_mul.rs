@@ -0,0 +1,133 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+
+/// Operands below this many bits go straight to num-bigint, above it one
+/// Karatsuba level hands its three half-size products to rayon. Per
+/// `bench_parallel_mul`, splitting a 10⁷ bit square down to 2²⁰ bits does
+/// about 1.5× the serial work of num-bigint's Toom-3, down to 2²² about 1.1×.
+pub const PARALLEL_THRESHOLD_BITS: u64 = 1 << 22;
+
+/// a·b, split across rayon threads once both operands are huge.
+pub fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    mul_above(a, b, threshold())
+}
+
+/// a², split across rayon threads once the operand is huge.
+pub fn square(a: &BigInt) -> BigInt {
+    square_above(a, threshold())
+}
+
+/// With a single thread num-bigint's own Toom-3 beats the Karatsuba split.
+fn threshold() -> u64 {
+    if rayon::current_num_threads() > 1 {
+        PARALLEL_THRESHOLD_BITS
+    } else {
+        u64::MAX
+    }
+}
+
+/// `mul` with an explicit threshold instead of `PARALLEL_THRESHOLD_BITS`.
+pub fn mul_above(a: &BigInt, b: &BigInt, threshold: u64) -> BigInt {
+    if a.bits().min(b.bits()) < threshold {
+        return a * b;
+    }
+    let magnitude = mul_magnitude(a.magnitude(), b.magnitude(), threshold);
+    BigInt::from_biguint(a.sign() * b.sign(), magnitude)
+}
+
+/// `square` with an explicit threshold instead of `PARALLEL_THRESHOLD_BITS`.
+pub fn square_above(a: &BigInt, threshold: u64) -> BigInt {
+    if a.bits() < threshold {
+        return a * a;
+    }
+    BigInt::from(square_magnitude(a.magnitude(), threshold))
+}
+
+/// x·y = z2·B² + (z1 − z2 − z0)·B + z0 with B = 2^(32·digits)
+fn mul_magnitude(x: &BigUint, y: &BigUint, threshold: u64) -> BigUint {
+    if x.bits().min(y.bits()) < threshold {
+        return x * y;
+    }
+
+    let digits = (x.bits().max(y.bits()) / 64) as usize;
+    let (x1, x0) = split(x, digits);
+    let (y1, y0) = split(y, digits);
+    let ((z2, z0), z1) = rayon::join(
+        || {
+            rayon::join(
+                || mul_magnitude(&x1, &y1, threshold),
+                || mul_magnitude(&x0, &y0, threshold),
+            )
+        },
+        || mul_magnitude(&(&x1 + &x0), &(&y1 + &y0), threshold),
+    );
+
+    combine(z2, z1, z0, digits)
+}
+
+fn square_magnitude(x: &BigUint, threshold: u64) -> BigUint {
+    if x.bits() < threshold {
+        return x * x;
+    }
+
+    let digits = (x.bits() / 64) as usize;
+    let (x1, x0) = split(x, digits);
+    let ((z2, z0), z1) = rayon::join(
+        || {
+            rayon::join(
+                || square_magnitude(&x1, threshold),
+                || square_magnitude(&x0, threshold),
+            )
+        },
+        || square_magnitude(&(&x1 + &x0), threshold),
+    );
+
+    combine(z2, z1, z0, digits)
+}
+
+/// (high, low) with x = high·2^(32·digits) + low
+fn split(x: &BigUint, digits: usize) -> (BigUint, BigUint) {
+    let limbs = x.to_u32_digits();
+    if limbs.len() <= digits {
+        return (BigUint::zero(), x.clone());
+    }
+    (
+        BigUint::from_slice(&limbs[digits..]),
+        BigUint::from_slice(&limbs[..digits]),
+    )
+}
+
+fn combine(z2: BigUint, z1: BigUint, z0: BigUint, digits: usize) -> BigUint {
+    let shift = 32 * digits;
+    let middle = z1 - &z2 - &z0;
+    (z2 << (2 * shift)) + (middle << shift) + z0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::doubling::fibo_pair;
+    use test_case::test_case;
+
+    fn fibo(n: i64) -> BigInt {
+        fibo_pair(&BigInt::from(n)).0
+    }
+
+    #[test_case(fibo(20_000), fibo(30_000); "balanced")]
+    #[test_case(-fibo(25_000), fibo(24_999); "negative")]
+    #[test_case(fibo(40_000), BigInt::from(7); "small factor")]
+    #[test_case(fibo(3_000), fibo(60_000); "unbalanced")]
+    #[test_case(BigInt::zero(), fibo(10_000); "zero")]
+    fn test_mul_matches_plain(a: BigInt, b: BigInt) {
+        assert_eq!(mul_above(&a, &b, 256), &a * &b);
+        assert_eq!(mul(&a, &b), &a * &b);
+    }
+
+    #[test_case(fibo(50_000); "positive")]
+    #[test_case(-fibo(33_333); "negative")]
+    #[test_case(BigInt::from(1) << 4_096u32; "power of two")]
+    fn test_square_matches_plain(a: BigInt) {
+        assert_eq!(square_above(&a, 256), &a * &a);
+        assert_eq!(square(&a), &a * &a);
+    }
+}
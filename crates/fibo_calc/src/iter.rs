@@ -0,0 +1,362 @@
+use crate::builder::FiboBuilder;
+use crate::implementation::doubling::{fibo_pair, jump_pair};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+/// Gaps up to this size are walked with plain additions, larger ones jump.
+const LINEAR_GAP: usize = 8;
+
+/// Lazy iterator over G(n) for the indices of a range. `nth` (and so `skip`)
+/// jumps with fast doubling, the back end walks G(n−1) = G(n+1) − G(n).
+#[derive(Debug, Clone)]
+pub struct FiboIter {
+    start_nums: (BigInt, BigInt),
+    range: Range<usize>,
+    /// (G(range.start), G(range.start + 1))
+    front: (BigInt, BigInt),
+    /// (G(range.end − 1), G(range.end)), computed on the first `next_back`
+    back: Option<(BigInt, BigInt)>,
+}
+
+impl FiboIter {
+    pub fn new(start_nums: (BigInt, BigInt), range: Range<usize>) -> Self {
+        let front = pair_at(&start_nums, range.start);
+        Self {
+            start_nums,
+            range,
+            front,
+            back: None,
+        }
+    }
+
+    /// Indices still to be produced.
+    pub fn indices(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// Start numbers default to (0, 1). The builder must have a range, and
+/// nothing else the background task would apply, or the terms would not be
+/// the ones `calc_background` produces.
+impl TryFrom<&FiboBuilder> for FiboIter {
+    type Error = String;
+
+    fn try_from(builder: &FiboBuilder) -> Result<Self, String> {
+        check_plain(builder)?;
+        let range = builder
+            .get_range_by_id()
+            .ok_or_else(|| String::from("FiboIter needs a range, use FiboSequence without one"))?;
+        Ok(Self::new(start_nums_or_default(builder), range))
+    }
+}
+
+impl Iterator for FiboIter {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<BigInt> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let after = &self.front.0 + &self.front.1;
+        let next = std::mem::replace(&mut self.front.1, after);
+        self.range.start += 1;
+        Some(std::mem::replace(&mut self.front.0, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.range.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<BigInt> {
+        if n >= self.range.len() {
+            self.range.start = self.range.end;
+            return None;
+        }
+
+        if n <= LINEAR_GAP {
+            for _ in 0..n {
+                self.next();
+            }
+        } else {
+            self.front = jump_pair(&self.front, &fibo_pair(&BigInt::from(n)));
+            self.range.start += n;
+        }
+        self.next()
+    }
+
+    fn last(mut self) -> Option<BigInt> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.range.len()
+    }
+}
+
+impl DoubleEndedIterator for FiboIter {
+    fn next_back(&mut self) -> Option<BigInt> {
+        if self.range.is_empty() {
+            return None;
+        }
+
+        let back = self
+            .back
+            .get_or_insert_with(|| pair_at(&self.start_nums, self.range.end - 1));
+        let before = &back.1 - &back.0;
+        let value = std::mem::replace(&mut back.0, before);
+        back.1 = value.clone();
+        self.range.end -= 1;
+        Some(value)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<BigInt> {
+        if n >= self.range.len() {
+            self.range.end = self.range.start;
+            return None;
+        }
+
+        if n <= LINEAR_GAP && self.back.is_some() {
+            for _ in 0..n {
+                self.next_back();
+            }
+        } else {
+            self.range.end -= n;
+            self.back = None;
+        }
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for FiboIter {}
+
+impl FusedIterator for FiboIter {}
+
+/// Lazy iterator over G(n) from some index on, without an end. Like
+/// `FiboIter`, `nth` (and so `skip`) jumps with fast doubling.
+#[derive(Debug, Clone)]
+pub struct FiboSequence {
+    index: usize,
+    /// (G(index), G(index + 1))
+    pair: (BigInt, BigInt),
+}
+
+impl FiboSequence {
+    pub fn new(start_nums: (BigInt, BigInt), start: usize) -> Self {
+        Self {
+            index: start,
+            pair: pair_at(&start_nums, start),
+        }
+    }
+
+    /// Index of the next term.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Starts at index 0 with start numbers defaulting to (0, 1). Anything else
+/// set on the builder is rejected, a range included: that is `FiboIter`.
+impl TryFrom<&FiboBuilder> for FiboSequence {
+    type Error = String;
+
+    fn try_from(builder: &FiboBuilder) -> Result<Self, String> {
+        check_plain(builder)?;
+        if builder.get_range_by_id().is_some() {
+            return Err(String::from("FiboSequence has no end, use FiboIter for a range"));
+        }
+        Ok(Self::new(start_nums_or_default(builder), 0))
+    }
+}
+
+impl Iterator for FiboSequence {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<BigInt> {
+        let after = &self.pair.0 + &self.pair.1;
+        let next = std::mem::replace(&mut self.pair.1, after);
+        self.index += 1;
+        Some(std::mem::replace(&mut self.pair.0, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<BigInt> {
+        if n > LINEAR_GAP {
+            self.pair = jump_pair(&self.pair, &fibo_pair(&BigInt::from(n)));
+            self.index += n;
+        } else {
+            for _ in 0..n {
+                self.next();
+            }
+        }
+        self.next()
+    }
+}
+
+impl FusedIterator for FiboSequence {}
+
+/// Settings the iterators cannot honour, as only the background task does.
+fn check_plain(builder: &FiboBuilder) -> Result<(), String> {
+    let ignored = [
+        (builder.get_index_selection().is_some(), "an index selection"),
+        (builder.get_value_range().is_some(), "a value range"),
+        (builder.get_search().is_some(), "a search"),
+        (!builder.get_filters().is_empty(), "filters"),
+        (builder.get_aggregate().is_some(), "an aggregate"),
+        (!builder.get_transforms().is_empty(), "transforms"),
+        (builder.get_verify(), "verification"),
+        (builder.get_checkpoint().is_some(), "a checkpoint"),
+        (builder.has_sink(), "a sink"),
+        (builder.get_polynomials().is_some(), "polynomials"),
+    ];
+    match ignored.iter().find(|(set, _)| *set) {
+        Some((_, setting)) => Err(format!("Iterators do not support {}", setting)),
+        None => Ok(()),
+    }
+}
+
+fn start_nums_or_default(builder: &FiboBuilder) -> (BigInt, BigInt) {
+    builder
+        .get_start_nums()
+        .unwrap_or_else(|| (BigInt::zero(), BigInt::one()))
+}
+
+/// (G(n), G(n+1)) by jumping from the start numbers.
+fn pair_at(start_nums: &(BigInt, BigInt), n: usize) -> (BigInt, BigInt) {
+    jump_pair(start_nums, &fibo_pair(&BigInt::from(n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use crate::polynomial::PolynomialKind;
+    use test_case::test_case;
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    fn expected(start_nums: &(BigInt, BigInt), range: Range<usize>) -> Vec<BigInt> {
+        range.map(|n| nth_term(start_nums, n)).collect()
+    }
+
+    #[test_case(seeds(0, 1), 0..30; "classic")]
+    #[test_case(seeds(2, 1), 5..40; "lucas")]
+    #[test_case(seeds(-17, 5), 1..25; "mixed")]
+    #[test_case(seeds(3, 3), 7..7; "empty")]
+    fn test_forward_and_backward(start_nums: (BigInt, BigInt), range: Range<usize>) {
+        let forward: Vec<BigInt> = FiboIter::new(start_nums.clone(), range.clone()).collect();
+        assert_eq!(forward, expected(&start_nums, range.clone()));
+
+        let mut backward: Vec<BigInt> = FiboIter::new(start_nums.clone(), range.clone())
+            .rev()
+            .collect();
+        backward.reverse();
+        assert_eq!(backward, forward);
+    }
+
+    #[test]
+    fn test_nth_and_skip_jump() {
+        let start_nums = seeds(2, 1);
+        let mut iter = FiboIter::new(start_nums.clone(), 0..10_000);
+
+        assert_eq!(iter.nth(3), Some(nth_term(&start_nums, 3)));
+        assert_eq!(iter.nth(5_000), Some(nth_term(&start_nums, 5_004)));
+        assert_eq!(iter.next(), Some(nth_term(&start_nums, 5_005)));
+        assert_eq!(iter.len(), 10_000 - 5_006);
+
+        let skipped: Vec<BigInt> = FiboIter::new(start_nums.clone(), 0..2_000)
+            .skip(1_990)
+            .step_by(3)
+            .collect();
+        assert_eq!(
+            skipped,
+            [1_990, 1_993, 1_996, 1_999].map(|n| nth_term(&start_nums, n))
+        );
+    }
+
+    #[test]
+    fn test_both_ends_meet() {
+        let start_nums = seeds(5, -3);
+        let mut iter = FiboIter::new(start_nums.clone(), 0..100);
+
+        assert_eq!(iter.next_back(), Some(nth_term(&start_nums, 99)));
+        assert_eq!(iter.nth_back(3), Some(nth_term(&start_nums, 95)));
+        assert_eq!(iter.nth_back(40), Some(nth_term(&start_nums, 54)));
+        assert_eq!(iter.nth(50), Some(nth_term(&start_nums, 50)));
+        assert_eq!(iter.len(), 3);
+
+        let rest: Vec<BigInt> = iter.by_ref().collect();
+        assert_eq!(rest, expected(&start_nums, 51..54));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_exhausted_nth() {
+        let mut iter = FiboIter::new(seeds(0, 1), 0..10);
+        assert_eq!(iter.nth(10), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn test_from_builder() {
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(10..15));
+        let values: Vec<BigInt> = FiboIter::try_from(&builder).unwrap().collect();
+        assert_eq!(values, expected(&seeds(0, 1), 10..15));
+        assert!(FiboSequence::try_from(&builder).is_err());
+
+        let builder = FiboBuilder::default();
+        assert!(FiboIter::try_from(&builder).is_err());
+        let mut unbounded = FiboSequence::try_from(&builder).unwrap();
+        assert_eq!(unbounded.nth(100), Some(nth_term(&seeds(0, 1), 100)));
+        assert_eq!(unbounded.next(), Some(nth_term(&seeds(0, 1), 101)));
+        assert_eq!(unbounded.index(), 102);
+        assert_eq!(unbounded.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_sequence_skip_jumps() {
+        let start_nums = seeds(-4, 9);
+        let values: Vec<BigInt> = FiboSequence::new(start_nums.clone(), 3)
+            .skip(5_000)
+            .take(3)
+            .collect();
+        assert_eq!(values, expected(&start_nums, 5_003..5_006));
+    }
+
+    #[test]
+    fn test_builder_settings_are_rejected() {
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..10));
+        builder.add_filter(|n| n.bit(0));
+        assert_eq!(
+            FiboIter::try_from(&builder).err(),
+            Some(String::from("Iterators do not support filters"))
+        );
+
+        let mut builder = FiboBuilder::default();
+        builder.set_verify(true);
+        assert_eq!(
+            FiboSequence::try_from(&builder).err(),
+            Some(String::from("Iterators do not support verification"))
+        );
+
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..10));
+        builder.set_polynomials(Some(PolynomialKind::Fibonacci));
+        assert_eq!(
+            FiboIter::try_from(&builder).err(),
+            Some(String::from("Iterators do not support polynomials"))
+        );
+    }
+}
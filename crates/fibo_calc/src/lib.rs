@@ -1,10 +1,59 @@
 extern crate alloc;
 
+mod aggregate;
+mod batch;
 mod builder;
 mod calculator;
+mod checkpoint;
+mod closed_form;
+pub mod coding;
+mod digits;
+mod format;
 mod implementation;
+mod iter;
+mod polynomial;
+mod radix;
+mod ratio;
+mod recurrence;
+#[cfg(feature = "rng")]
+mod rng;
+mod search;
+mod selection;
+mod set_ops;
+mod sink;
 mod task;
+mod transform;
+mod value_range;
+mod verify;
+mod word;
 
+pub use aggregate::{AggregateMode, FiboAggregate};
 pub use builder::FiboBuilder;
-pub use calculator::FiboCalc;
+pub use calculator::{FiboCalc, ImplementationFibo};
+pub use checkpoint::{
+    Checkpoint, CheckpointConfig, CheckpointJob, DEFAULT_CHECKPOINT_INTERVAL, results_path,
+};
+pub use closed_form::{ClosedForm, Surd};
+pub use digits::{DigitSummary, digit_count, leading_digits, nth_term_mod, trailing_digits};
+pub use format::{Grouping, Notation, NumberFormat, truncate_middle};
+pub use implementation::lineal::LinealFibo;
+pub use implementation::parallel_mul;
+pub use iter::{FiboIter, FiboSequence};
+pub use polynomial::{FiboPolynomial, PolynomialKind, PolynomialSequence};
+pub use radix::{clear_decimal_cache, decimal_string, first_digits, last_digits};
+pub use ratio::{RatioConvergent, continued_fraction, convergents, golden_ratio, to_decimal_string};
+pub use recurrence::LinearRecurrence;
+#[cfg(feature = "rng")]
+pub use rng::{Additive, LONG_LAG, LagOperation, LaggedFibo, Multiplicative, SHORT_LAG};
+pub use search::{DEFAULT_INDEX_LIMIT, FirstMatches, SearchProgress};
+pub use selection::{IndexSelection, SelectionPart};
+pub use set_ops::{SetOperation, combine_sequences};
+pub use sink::{
+    BINARY_MAGIC, BinaryReader, BinarySink, BoxedSink, CsvColumn, CsvSink, NdjsonSink, ResultSink,
+    TextSink,
+};
 pub use task::{FiboTaskReceiver, FiboTaskResult};
+pub use transform::{Modulus, Transform, TransformStage};
+pub use value_range::ValueRange;
+pub use verify::{VERIFY_PRIMES, Verdict, Verification, Verifier};
+pub use word::{DEFAULT_ALPHABET, FiboWord, WordChars};
@@ -0,0 +1,219 @@
+use crate::selection::IndexSelection;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialKind {
+    /// F₀ = 0, F₁ = 1
+    Fibonacci,
+    /// L₀ = 2, L₁ = x
+    Lucas,
+}
+
+/// Polynomial with coefficients stored from the constant term upwards.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FiboPolynomial {
+    coefficients: Vec<BigInt>,
+}
+
+impl FiboPolynomial {
+    pub fn new(coefficients: Vec<BigInt>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+
+    pub fn coefficients(&self) -> &[BigInt] {
+        &self.coefficients
+    }
+
+    /// Coefficient of xᵏ
+    pub fn coefficient(&self, k: usize) -> BigInt {
+        self.coefficients.get(k).cloned().unwrap_or_default()
+    }
+
+    /// `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn evaluate(&self, x: &BigInt) -> BigInt {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(BigInt::zero(), |acc, coefficient| acc * x + coefficient)
+    }
+
+    /// x·self + other
+    fn shift_add(&self, other: &FiboPolynomial) -> FiboPolynomial {
+        let len = (self.coefficients.len() + 1).max(other.coefficients.len());
+        let coefficients = (0..len)
+            .map(|k| {
+                let shifted = k
+                    .checked_sub(1)
+                    .map(|k| self.coefficient(k))
+                    .unwrap_or_default();
+                shifted + other.coefficient(k)
+            })
+            .collect();
+        FiboPolynomial::new(coefficients)
+    }
+
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(Zero::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl Display for FiboPolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+
+            let sign = match (first, coefficient.is_negative()) {
+                (true, true) => "-",
+                (true, false) => "",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+            let magnitude = coefficient.abs();
+            let magnitude = if magnitude.is_one() && power > 0 {
+                String::new()
+            } else {
+                magnitude.to_string()
+            };
+            let variable = match power {
+                0 => String::new(),
+                1 => String::from("x"),
+                _ => format!("x^{}", power),
+            };
+
+            write!(f, "{}{}{}", sign, magnitude, variable)?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// Pₙ(x) = x·Pₙ₋₁(x) + Pₙ₋₂(x)
+pub struct PolynomialSequence {
+    pre_last: FiboPolynomial,
+    last: FiboPolynomial,
+}
+
+impl PolynomialSequence {
+    pub fn new(kind: PolynomialKind) -> Self {
+        let (first, second) = match kind {
+            PolynomialKind::Fibonacci => (vec![], vec![BigInt::one()]),
+            PolynomialKind::Lucas => (vec![BigInt::from(2)], vec![BigInt::zero(), BigInt::one()]),
+        };
+
+        Self {
+            pre_last: FiboPolynomial::new(first),
+            last: FiboPolynomial::new(second),
+        }
+    }
+}
+
+impl Iterator for PolynomialSequence {
+    type Item = FiboPolynomial;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.last.shift_add(&self.pre_last);
+        let current =
+            std::mem::replace(&mut self.pre_last, std::mem::replace(&mut self.last, next));
+        Some(current)
+    }
+}
+
+pub(crate) fn select_polynomials(
+    kind: PolynomialKind, selection: &IndexSelection,
+) -> impl Iterator<Item = FiboPolynomial> + '_ {
+    let mut sequence = PolynomialSequence::new(kind).enumerate();
+    selection.iter().filter_map(move |index| {
+        sequence
+            .find(|(i, _)| *i == index)
+            .map(|(_, polynomial)| polynomial)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    #[test_case(PolynomialKind::Fibonacci, 0, "0"; "fibonacci zero")]
+    #[test_case(PolynomialKind::Fibonacci, 1, "1"; "fibonacci one")]
+    #[test_case(PolynomialKind::Fibonacci, 5, "x^4 + 3x^2 + 1"; "fibonacci five")]
+    #[test_case(PolynomialKind::Fibonacci, 6, "x^5 + 4x^3 + 3x"; "fibonacci six")]
+    #[test_case(PolynomialKind::Lucas, 0, "2"; "lucas zero")]
+    #[test_case(PolynomialKind::Lucas, 4, "x^4 + 4x^2 + 2"; "lucas four")]
+    fn test_display(kind: PolynomialKind, n: usize, expected: &str) {
+        let polynomial = PolynomialSequence::new(kind).nth(n).unwrap();
+        assert_eq!(polynomial.to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_negative() {
+        let polynomial = FiboPolynomial::new([-1, 0, 1, -3].map(BigInt::from).to_vec());
+        assert_eq!(polynomial.to_string(), "-3x^3 + x^2 - 1");
+    }
+
+    #[test]
+    fn test_evaluate_at_one_gives_numbers() {
+        let fibonacci = PolynomialSequence::new(PolynomialKind::Fibonacci);
+        let lucas = PolynomialSequence::new(PolynomialKind::Lucas);
+        let one = BigInt::one();
+
+        for (n, (f, l)) in fibonacci.zip(lucas).take(30).enumerate() {
+            assert_eq!(
+                f.evaluate(&one),
+                nth_term(&(BigInt::zero(), BigInt::one()), n)
+            );
+            assert_eq!(
+                l.evaluate(&one),
+                nth_term(&(BigInt::from(2), BigInt::one()), n)
+            );
+        }
+    }
+
+    #[test]
+    fn test_evaluate_at_two_gives_pell_numbers() {
+        let pell: Vec<BigInt> = PolynomialSequence::new(PolynomialKind::Fibonacci)
+            .take(7)
+            .map(|p| p.evaluate(&BigInt::from(2)))
+            .collect();
+        assert_eq!(pell, [0, 1, 2, 5, 12, 29, 70].map(BigInt::from));
+    }
+
+    #[test]
+    fn test_coefficients() {
+        let polynomial = PolynomialSequence::new(PolynomialKind::Fibonacci)
+            .nth(7)
+            .unwrap();
+        assert_eq!(polynomial.degree(), Some(6));
+        assert_eq!(polynomial.coefficient(4), BigInt::from(5));
+        assert_eq!(polynomial.coefficient(3), BigInt::zero());
+        assert_eq!(polynomial.coefficient(100), BigInt::zero());
+    }
+
+    #[test]
+    fn test_select_polynomials() {
+        let selection: IndexSelection = "2, 4..7 step 2".parse().unwrap();
+        let rendered: Vec<String> = select_polynomials(PolynomialKind::Fibonacci, &selection)
+            .map(|polynomial| polynomial.to_string())
+            .collect();
+        assert_eq!(rendered, ["x", "x^3 + 2x", "x^5 + 4x^3 + 3x"]);
+    }
+}
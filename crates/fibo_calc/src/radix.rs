@@ -0,0 +1,277 @@
+use crate::implementation::parallel_mul::mul;
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Signed, Zero};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Digits of 10^… at level 0 of the power tree; values below its square are
+/// converted by num-bigint directly.
+const LEAF_DIGITS: usize = 1024;
+/// Divisors up to this many bits get their reciprocal by plain division.
+const RECIPROCAL_BASE_BITS: u64 = 4096;
+/// Halves of values above this many bits are converted on separate threads.
+const PARALLEL_BITS: u64 = 1 << 18;
+
+/// 10^(LEAF_DIGITS·2^level), shared by every conversion until
+/// `clear_decimal_cache`.
+static POWERS: Mutex<Vec<Arc<PowerOfTen>>> = Mutex::new(Vec::new());
+
+struct PowerOfTen {
+    digits: usize,
+    value: BigInt,
+    /// ⌊2^(2·bits) / value⌋
+    reciprocal: BigInt,
+}
+
+impl PowerOfTen {
+    fn new(digits: usize, value: BigInt) -> Self {
+        let reciprocal = reciprocal(&value);
+        Self {
+            digits,
+            value,
+            reciprocal,
+        }
+    }
+
+    /// (⌊x / value⌋, x mod value) for 0 ≤ x < value², by Barrett reduction:
+    /// the estimate from the top s + 1 bits of x is short by at most 2.
+    fn div_rem(&self, x: &BigInt) -> (BigInt, BigInt) {
+        let s = self.value.bits();
+        let mut quotient = mul(&(x >> (s - 1)), &self.reciprocal) >> (s + 1);
+        let mut rest = x - mul(&quotient, &self.value);
+        while rest >= self.value {
+            rest -= &self.value;
+            quotient += 1;
+        }
+        (quotient, rest)
+    }
+}
+
+/// Decimal string by divide and conquer over cached powers of ten, with
+/// Newton reciprocals so every step is a multiplication.
+pub fn decimal_string(value: &BigInt) -> String {
+    let magnitude = value.abs();
+    let powers = powers_for(magnitude.bits());
+    let digits = convert(&magnitude, &powers, powers.len() - 1, None);
+    if value.is_negative() {
+        format!("-{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// First `k` digits of |value|, all of them when it has fewer.
+pub fn first_digits(value: &BigInt, k: usize) -> String {
+    leading_in_base(value.magnitude(), 10, k).0
+}
+
+/// Last `k` digits of |value|, zero padded unless it has fewer.
+pub fn last_digits(value: &BigInt, k: usize) -> String {
+    trailing_in_base(value.magnitude(), 10, k)
+}
+
+/// First `k` digits of `magnitude` in `base` and how many digits it has,
+/// converting only a few more than `k`.
+pub(crate) fn leading_in_base(magnitude: &BigUint, base: u32, k: usize) -> (String, u64) {
+    // (bits − 1)·log_base(2) is at most the digit count minus one; one more
+    // digit of slack covers the float error
+    let digits_below = (magnitude.bits().saturating_sub(1)) as f64 / f64::from(base).log2();
+    let dropped = (digits_below as u64).saturating_sub(k as u64 + 2);
+    let head = magnitude / BigUint::from(base).pow(dropped as u32);
+    let head = head.to_str_radix(base);
+    let count = dropped + head.len() as u64;
+    (head.chars().take(k).collect(), count)
+}
+
+/// Last `k` digits of `magnitude` in `base`, zero padded unless it has fewer.
+pub(crate) fn trailing_in_base(magnitude: &BigUint, base: u32, k: usize) -> String {
+    let modulus = BigUint::from(base).pow(k as u32);
+    if magnitude < &modulus {
+        magnitude.to_str_radix(base)
+    } else {
+        format!("{:0>width$}", (magnitude % modulus).to_str_radix(base), width = k)
+    }
+}
+
+/// Drops the cached powers of ten, which take about as much memory as the
+/// largest value converted so far.
+pub fn clear_decimal_cache() {
+    cached_powers().clear();
+}
+
+fn cached_powers() -> MutexGuard<'static, Vec<Arc<PowerOfTen>>> {
+    POWERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Levels 0..=L of the power tree with value < (10^…)² at level L. Missing
+/// levels are built without holding the lock, then published.
+fn powers_for(bits: u64) -> Vec<Arc<PowerOfTen>> {
+    let mut powers = cached_powers().clone();
+    let cached = powers.len();
+    if powers.is_empty() {
+        let leaf = BigInt::from(10).pow(LEAF_DIGITS as u32);
+        powers.push(Arc::new(PowerOfTen::new(LEAF_DIGITS, leaf)));
+    }
+
+    let mut level = 0;
+    loop {
+        if level == powers.len() {
+            let below = Arc::clone(&powers[level - 1]);
+            let value = mul(&below.value, &below.value);
+            powers.push(Arc::new(PowerOfTen::new(below.digits * 2, value)));
+        }
+        if bits <= 2 * (powers[level].value.bits() - 1) {
+            break;
+        }
+        level += 1;
+    }
+
+    if powers.len() > cached {
+        let mut shared = cached_powers();
+        if shared.len() < powers.len() {
+            *shared = powers.clone();
+        }
+    }
+    powers.truncate(level + 1);
+    powers
+}
+
+/// Digits of 0 ≤ x < (10^…)² at `level`, zero padded to `width`.
+fn convert(x: &BigInt, powers: &[Arc<PowerOfTen>], level: usize, width: Option<usize>) -> String {
+    if level == 0 {
+        return match width {
+            Some(width) => format!("{:0>width$}", x.to_string(), width = width),
+            None => x.to_string(),
+        };
+    }
+
+    let power = &powers[level];
+    let (high, low) = power.div_rem(x);
+    if width.is_none() && high.is_zero() {
+        return convert(&low, powers, level - 1, None);
+    }
+    let high_width = width.map(|width| width - power.digits);
+    let (high, low) = if x.bits() > PARALLEL_BITS {
+        rayon::join(
+            || convert(&high, powers, level - 1, high_width),
+            || convert(&low, powers, level - 1, Some(power.digits)),
+        )
+    } else {
+        (
+            convert(&high, powers, level - 1, high_width),
+            convert(&low, powers, level - 1, Some(power.digits)),
+        )
+    };
+
+    high + &low
+}
+
+/// ⌊2^(2s) / p⌋ for p of s bits: the reciprocal of p's top half scaled up,
+/// one Newton step, then an exact correction.
+fn reciprocal(p: &BigInt) -> BigInt {
+    let s = p.bits();
+    let unit = BigInt::one() << (2 * s);
+    if s <= RECIPROCAL_BASE_BITS {
+        return unit / p;
+    }
+
+    let h = s / 2 + 1;
+    let mut r = reciprocal(&(p >> (s - h))) << (s - h);
+    let error = &unit - mul(p, &r);
+    r += mul(&r, &error) >> (2 * s);
+
+    let mut error = &unit - mul(p, &r);
+    while error.is_negative() {
+        r -= 1;
+        error += p;
+    }
+    while &error >= p {
+        r += 1;
+        error -= p;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::doubling::fibo_pair;
+    use test_case::test_case;
+
+    fn fibo(n: i64) -> BigInt {
+        fibo_pair(&BigInt::from(n)).0
+    }
+
+    fn ten_pow(n: u32) -> BigInt {
+        BigInt::from(10).pow(n)
+    }
+
+    #[test_case(BigInt::ZERO; "zero")]
+    #[test_case(BigInt::from(-12_345); "small negative")]
+    #[test_case(fibo(9_000); "one level")]
+    #[test_case(-fibo(40_000); "several levels")]
+    #[test_case(ten_pow(4_096); "power on a level boundary")]
+    #[test_case(ten_pow(4_096) - 1; "nines below a boundary")]
+    #[test_case(ten_pow(6_000) + 7; "inner zeros")]
+    fn test_decimal_string_matches_plain(value: BigInt) {
+        assert_eq!(decimal_string(&value), value.to_string());
+    }
+
+    #[test]
+    fn test_conversions_around_a_cleared_cache() {
+        let (large, small) = (fibo(60_000), fibo(9_000));
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let value = if i % 2 == 0 { large.clone() } else { small.clone() };
+                std::thread::spawn(move || decimal_string(&value) == value.to_string())
+            })
+            .collect();
+        clear_decimal_cache();
+        assert!(threads.into_iter().all(|thread| thread.join().unwrap()));
+
+        clear_decimal_cache();
+        assert_eq!(decimal_string(&small), small.to_string());
+        assert!(powers_for(large.bits()).len() > powers_for(small.bits()).len());
+    }
+
+    #[test_case(fibo(30_000); "fibonacci")]
+    #[test_case(ten_pow(4_000); "power of ten")]
+    #[test_case(ten_pow(4_000) - 1; "just below a power of ten")]
+    fn test_reciprocal_is_exact(p: BigInt) {
+        let unit = BigInt::one() << (2 * p.bits());
+        assert_eq!(reciprocal(&p), unit / &p);
+    }
+
+    #[test_case(BigInt::from(832_040), 3, "832", "040"; "short")]
+    #[test_case(BigInt::from(-55), 5, "55", "55"; "fewer digits than asked")]
+    #[test_case(BigInt::ZERO, 2, "0", "0"; "zero")]
+    #[test_case(ten_pow(100), 4, "1000", "0000"; "power of ten")]
+    fn test_first_and_last_digits(value: BigInt, k: usize, first: &str, last: &str) {
+        assert_eq!(first_digits(&value, k), first);
+        assert_eq!(last_digits(&value, k), last);
+    }
+
+    #[test_case(2; "binary")]
+    #[test_case(16; "hex")]
+    #[test_case(36; "base 36")]
+    fn test_leading_and_trailing_in_base(base: u32) {
+        for value in [fibo(20_000), BigInt::from(base).pow(3_000), BigInt::from(base - 1)] {
+            let digits = value.magnitude().to_str_radix(base);
+            let (leading, count) = leading_in_base(value.magnitude(), base, 30);
+            assert_eq!(leading, digits[..digits.len().min(30)]);
+            assert_eq!(count, digits.len() as u64);
+            let trailing = trailing_in_base(value.magnitude(), base, 30);
+            assert_eq!(trailing, digits[digits.len().saturating_sub(30)..]);
+        }
+    }
+
+    #[test]
+    fn test_first_and_last_digits_of_large_values() {
+        for value in [fibo(25_000), ten_pow(5_000) - 1, -fibo(12_345)] {
+            let digits = value.magnitude().to_string();
+            assert_eq!(first_digits(&value, 40), digits[..40]);
+            assert_eq!(last_digits(&value, 40), digits[digits.len() - 40..]);
+        }
+    }
+}
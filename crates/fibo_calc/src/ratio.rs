@@ -0,0 +1,154 @@
+use crate::implementation::matmul::nth_term;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+const GUARD_DIGITS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RatioConvergent {
+    pub index: usize,
+    /// Exact G(n+1)/G(n)
+    pub ratio: BigRational,
+    pub decimal: String,
+    /// |ratio − φ| truncated to the same number of digits as `decimal`
+    pub error: String,
+    pub continued_fraction: Vec<BigInt>,
+    pub convergents: Vec<BigRational>,
+}
+
+impl RatioConvergent {
+    /// Returns `None` when G(n) is zero and the ratio is undefined.
+    pub fn new(start_nums: &(BigInt, BigInt), n: usize, digits: usize) -> Option<Self> {
+        let denominator = nth_term(start_nums, n);
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let ratio = BigRational::new(nth_term(start_nums, n + 1), denominator);
+        let continued_fraction = continued_fraction(&ratio);
+
+        Some(Self {
+            index: n,
+            decimal: to_decimal_string(&ratio, digits),
+            error: to_decimal_string(&(&ratio - golden_ratio(digits)).abs(), digits),
+            convergents: convergents(&continued_fraction),
+            continued_fraction,
+            ratio,
+        })
+    }
+}
+
+/// φ as a rational accurate to `digits` decimal places (plus guard digits).
+pub fn golden_ratio(digits: usize) -> BigRational {
+    let scale = BigInt::from(10).pow((digits + GUARD_DIGITS) as u32);
+    let sqrt5 = (BigInt::from(5) * &scale * &scale).sqrt();
+    BigRational::new(&scale + sqrt5, scale * 2)
+}
+
+/// Decimal representation truncated toward zero after `digits` places.
+pub fn to_decimal_string(value: &BigRational, digits: usize) -> String {
+    let scale = BigRational::from_integer(BigInt::from(10).pow(digits as u32));
+    let scaled = (value.abs() * scale).trunc().to_integer();
+
+    let mut text = scaled.to_string();
+    if digits > 0 {
+        if text.len() <= digits {
+            text = "0".repeat(digits + 1 - text.len()) + &text;
+        }
+        text.insert(text.len() - digits, '.');
+    }
+    if value.is_negative() && !scaled.is_zero() {
+        text.insert(0, '-');
+    }
+
+    text
+}
+
+pub fn continued_fraction(value: &BigRational) -> Vec<BigInt> {
+    let mut numerator = value.numer().clone();
+    let mut denominator = value.denom().clone();
+    let mut terms = Vec::new();
+
+    while !denominator.is_zero() {
+        let (term, remainder) = numerator.div_mod_floor(&denominator);
+        terms.push(term);
+        numerator = std::mem::replace(&mut denominator, remainder);
+    }
+
+    terms
+}
+
+pub fn convergents(continued_fraction: &[BigInt]) -> Vec<BigRational> {
+    let (mut h_prev, mut h) = (BigInt::zero(), BigInt::one());
+    let (mut k_prev, mut k) = (BigInt::one(), BigInt::zero());
+
+    continued_fraction
+        .iter()
+        .map(|term| {
+            let h_next = term * &h + &h_prev;
+            let k_next = term * &k + &k_prev;
+            h_prev = std::mem::replace(&mut h, h_next);
+            k_prev = std::mem::replace(&mut k, k_next);
+            BigRational::new(h.clone(), k.clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn rational(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    #[test]
+    fn test_classic_convergent() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        let convergent = RatioConvergent::new(&start_nums, 20, 10).unwrap();
+
+        assert_eq!(convergent.ratio, rational(10946, 6765));
+        assert_eq!(convergent.decimal, "1.6180339985");
+        assert_eq!(convergent.error, "0.0000000097");
+        assert_eq!(convergent.convergents.last(), Some(&convergent.ratio));
+        assert!(
+            convergent.continued_fraction[..convergent.continued_fraction.len() - 1]
+                .iter()
+                .all(BigInt::is_one)
+        );
+    }
+
+    #[test]
+    fn test_zero_denominator() {
+        let start_nums = (BigInt::from(0), BigInt::from(1));
+        assert!(RatioConvergent::new(&start_nums, 0, 10).is_none());
+    }
+
+    #[test]
+    fn test_custom_seeds_converge() {
+        let start_nums = (BigInt::from(7), BigInt::from(-3));
+        let convergent = RatioConvergent::new(&start_nums, 60, 20).unwrap();
+        assert!(convergent.decimal.starts_with("1.6180339887498948"));
+    }
+
+    #[test_case(rational(8, 5), 3, "1.600"; "simple")]
+    #[test_case(rational(-1, 3), 4, "-0.3333"; "negative")]
+    #[test_case(rational(-1, 30000), 2, "0.00"; "negative rounds to zero")]
+    #[test_case(rational(7, 1), 0, "7"; "no digits")]
+    fn test_to_decimal_string(value: BigRational, digits: usize, expected: &str) {
+        assert_eq!(to_decimal_string(&value, digits), expected);
+    }
+
+    #[test_case(rational(8, 5), &[1, 1, 1, 2]; "fibonacci ratio")]
+    #[test_case(rational(-7, 3), &[-3, 1, 2]; "negative")]
+    #[test_case(rational(4, 1), &[4]; "integer")]
+    fn test_continued_fraction(value: BigRational, expected: &[i64]) {
+        let expected: Vec<BigInt> = expected.iter().map(|&n| BigInt::from(n)).collect();
+        let terms = continued_fraction(&value);
+        assert_eq!(terms, expected);
+        assert_eq!(convergents(&terms).last(), Some(&value));
+    }
+}
@@ -0,0 +1,271 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+use std::fmt::Display;
+
+/// Order of xₙ = xₙ₋₁ + xₙ₋₂
+const FIBONACCI_ORDER: usize = 2;
+
+/// Shortest recurrence xₙ = c₁·xₙ₋₁ + … + c_L·xₙ₋L that reproduces the
+/// sample terms, found with Berlekamp–Massey over the rationals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRecurrence {
+    coefficients: Vec<BigRational>,
+    seeds: Vec<BigInt>,
+    fibonacci_start: Option<(BigInt, BigInt)>,
+    samples: usize,
+}
+
+impl LinearRecurrence {
+    pub fn identify(terms: &[BigInt]) -> Self {
+        let terms: Vec<BigRational> = terms.iter().cloned().map(BigRational::from).collect();
+        let connection = berlekamp_massey(&terms);
+
+        let coefficients: Vec<BigRational> = connection.iter().skip(1).map(|c| -c).collect();
+        let seeds = terms[..coefficients.len()]
+            .iter()
+            .map(BigRational::to_integer)
+            .collect();
+
+        Self {
+            coefficients,
+            seeds,
+            fibonacci_start: fibonacci_start(&terms),
+            samples: terms.len(),
+        }
+    }
+
+    /// c₁ … c_L, the weight of xₙ₋₁ first.
+    pub fn coefficients(&self) -> &[BigRational] {
+        &self.coefficients
+    }
+
+    /// x₀ … x_{L−1}
+    pub fn seeds(&self) -> &[BigInt] {
+        &self.seeds
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Whether the samples pin the recurrence down: an order L fit needs 2L
+    /// terms, as any 2L − 1 of them fit some recurrence of that order.
+    pub fn is_determined(&self) -> bool {
+        self.samples >= 2 * self.order()
+    }
+
+    /// Whether every coefficient is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.coefficients.iter().all(BigRational::is_integer)
+    }
+
+    /// Start numbers (a, b) when at least four terms satisfy
+    /// xₙ = xₙ₋₁ + xₙ₋₂, even if a shorter recurrence also fits them.
+    pub fn fibonacci_start(&self) -> Option<&(BigInt, BigInt)> {
+        self.fibonacci_start.as_ref()
+    }
+
+    /// First `count` terms produced by the recurrence.
+    pub fn generate(&self, count: usize) -> Vec<BigRational> {
+        let mut terms: Vec<BigRational> = self
+            .seeds
+            .iter()
+            .take(count)
+            .cloned()
+            .map(BigRational::from)
+            .collect();
+
+        while terms.len() < count {
+            let next = self
+                .coefficients
+                .iter()
+                .zip(terms.iter().rev())
+                .map(|(c, x)| c * x)
+                .fold(BigRational::zero(), |acc, term| acc + term);
+            terms.push(next);
+        }
+
+        terms
+    }
+}
+
+impl Display for LinearRecurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x(n) =")?;
+
+        let mut first = true;
+        for (lag, coefficient) in self.coefficients.iter().enumerate() {
+            if coefficient.is_zero() {
+                continue;
+            }
+
+            let sign = match (first, coefficient.is_negative()) {
+                (true, true) => " -",
+                (true, false) => " ",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+            let magnitude = coefficient.abs();
+            let magnitude = if magnitude.is_one() {
+                String::new()
+            } else {
+                format!("{}·", magnitude)
+            };
+
+            write!(f, "{}{}x(n-{})", sign, magnitude, lag + 1)?;
+            first = false;
+        }
+
+        if first {
+            write!(f, " 0")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Connection polynomial C with C₀ = 1 and Σ Cᵢ·xₙ₋ᵢ = 0 for every n ≥ L.
+fn berlekamp_massey(terms: &[BigRational]) -> Vec<BigRational> {
+    let mut current = vec![BigRational::one()];
+    let mut previous = vec![BigRational::one()];
+    let mut previous_discrepancy = BigRational::one();
+    let mut length = 0;
+    let mut shift = 1;
+
+    for n in 0..terms.len() {
+        let discrepancy = current
+            .iter()
+            .enumerate()
+            .map(|(i, c)| c * &terms[n - i])
+            .fold(BigRational::zero(), |acc, term| acc + term);
+
+        if discrepancy.is_zero() {
+            shift += 1;
+            continue;
+        }
+
+        let factor = &discrepancy / &previous_discrepancy;
+        let mut next = current.clone();
+        if next.len() < previous.len() + shift {
+            next.resize(previous.len() + shift, BigRational::zero());
+        }
+        for (i, b) in previous.iter().enumerate() {
+            next[i + shift] -= &factor * b;
+        }
+
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = std::mem::replace(&mut current, next);
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            current = next;
+            shift += 1;
+        }
+    }
+
+    current.resize(length + 1, BigRational::zero());
+    current
+}
+
+fn fibonacci_start(terms: &[BigRational]) -> Option<(BigInt, BigInt)> {
+    if terms.len() < 2 * FIBONACCI_ORDER || !terms.iter().all(BigRational::is_integer) {
+        return None;
+    }
+
+    let fits = terms.windows(3).all(|w| w[2] == &w[0] + &w[1]);
+    fits.then(|| (terms[0].to_integer(), terms[1].to_integer()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn ints(values: &[i64]) -> Vec<BigInt> {
+        values.iter().copied().map(BigInt::from).collect()
+    }
+
+    fn rationals(values: &[i64]) -> Vec<BigRational> {
+        values
+            .iter()
+            .copied()
+            .map(|v| BigRational::from(BigInt::from(v)))
+            .collect()
+    }
+
+    #[test]
+    fn test_fibonacci_like() {
+        let start_nums = (BigInt::from(-4), BigInt::from(7));
+        let terms: Vec<BigInt> = (0..12).map(|n| nth_term(&start_nums, n)).collect();
+
+        let recurrence = LinearRecurrence::identify(&terms);
+        assert_eq!(recurrence.coefficients(), rationals(&[1, 1]));
+        assert_eq!(recurrence.seeds(), ints(&[-4, 7]));
+        assert_eq!(recurrence.fibonacci_start(), Some(&start_nums));
+        assert_eq!(recurrence.to_string(), "x(n) = x(n-1) + x(n-2)");
+    }
+
+    #[test_case(&[1, 2, 4, 8, 16, 32], &[2], "x(n) = 2·x(n-1)"; "geometric")]
+    #[test_case(&[0, 1, 2, 5, 12, 29, 70], &[2, 1], "x(n) = 2·x(n-1) + x(n-2)"; "pell")]
+    #[test_case(&[0, 0, 1, 1, 2, 4, 7, 13, 24], &[1, 1, 1], "x(n) = x(n-1) + x(n-2) + x(n-3)"; "tribonacci")]
+    #[test_case(&[3, 0, 2, 3, 2, 5, 5, 7, 10], &[0, 1, 1], "x(n) = x(n-2) + x(n-3)"; "perrin")]
+    #[test_case(&[1, -1, 1, -1, 1], &[-1], "x(n) = -x(n-1)"; "alternating")]
+    #[test_case(&[0, 0, 0, 0], &[], "x(n) = 0"; "zeros")]
+    fn test_known_recurrences(terms: &[i64], coefficients: &[i64], display: &str) {
+        let recurrence = LinearRecurrence::identify(&ints(terms));
+        assert_eq!(recurrence.coefficients(), rationals(coefficients));
+        assert_eq!(recurrence.to_string(), display);
+        assert!(recurrence.is_integer());
+        assert_eq!(recurrence.generate(terms.len()), rationals(terms));
+    }
+
+    #[test]
+    fn test_rational_coefficients() {
+        // xₙ = xₙ₋₁ / 2 scaled to stay integral over the sample
+        let recurrence = LinearRecurrence::identify(&ints(&[64, 32, 16, 8, 4]));
+        let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+        assert_eq!(recurrence.coefficients(), [half]);
+        assert!(!recurrence.is_integer());
+        assert_eq!(recurrence.to_string(), "x(n) = 1/2·x(n-1)");
+    }
+
+    #[test]
+    fn test_non_fibonacci_is_reported() {
+        let recurrence = LinearRecurrence::identify(&ints(&[1, 2, 4, 8]));
+        assert_eq!(recurrence.fibonacci_start(), None);
+
+        // Two or three terms fit too many start numbers to report any
+        let recurrence = LinearRecurrence::identify(&ints(&[5, 9]));
+        assert_eq!(recurrence.fibonacci_start(), None);
+        let recurrence = LinearRecurrence::identify(&ints(&[2, 1, 3]));
+        assert_eq!(recurrence.fibonacci_start(), None);
+    }
+
+    #[test_case(&[5, 9] => true; "order one")]
+    #[test_case(&[2, 1, 3] => false; "order two from three terms")]
+    #[test_case(&[2, 1, 3, 4] => true; "order two from four terms")]
+    #[test_case(&[0, 0, 1] => false; "order three")]
+    fn test_is_determined(terms: &[i64]) -> bool {
+        LinearRecurrence::identify(&ints(terms)).is_determined()
+    }
+
+    #[test]
+    fn test_generate_continues_sequence() {
+        let recurrence = LinearRecurrence::identify(&ints(&[2, 1, 3, 4, 7]));
+        let lucas: Vec<BigRational> = (0..20)
+            .map(|n| BigRational::from(nth_term(&(BigInt::from(2), BigInt::one()), n)))
+            .collect();
+        assert_eq!(recurrence.generate(20), lucas);
+    }
+
+    #[test]
+    fn test_empty() {
+        let recurrence = LinearRecurrence::identify(&[]);
+        assert_eq!(recurrence.order(), 0);
+        assert!(recurrence.seeds().is_empty());
+        assert_eq!(recurrence.fibonacci_start(), None);
+    }
+}
@@ -0,0 +1,285 @@
+use rand_core::{RngCore, SeedableRng, impls};
+use std::marker::PhantomData;
+
+/// Classic Knuth lags: xₙ = xₙ₋₂₄ ∘ xₙ₋₅₅ (mod 2⁶⁴)
+pub const SHORT_LAG: usize = 24;
+pub const LONG_LAG: usize = 55;
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+pub trait LagOperation {
+    fn combine(short: u64, long: u64) -> u64;
+
+    /// Whether `state` can start a full period sequence.
+    fn validate(state: &[u64]) -> Result<(), String>;
+
+    /// Turns an arbitrary seed word into a valid state value.
+    fn prepare(word: u64) -> u64;
+}
+
+/// xₙ = xₙ₋ⱼ + xₙ₋ₖ (mod 2⁶⁴)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Additive;
+
+/// xₙ = xₙ₋ⱼ · xₙ₋ₖ (mod 2⁶⁴), on odd values only
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multiplicative;
+
+impl LagOperation for Additive {
+    fn combine(short: u64, long: u64) -> u64 {
+        short.wrapping_add(long)
+    }
+
+    fn validate(state: &[u64]) -> Result<(), String> {
+        if state.iter().all(|value| value % 2 == 0) {
+            return Err(String::from(
+                "Additive lags need at least one odd state value",
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare(word: u64) -> u64 {
+        word
+    }
+}
+
+impl LagOperation for Multiplicative {
+    fn combine(short: u64, long: u64) -> u64 {
+        short.wrapping_mul(long)
+    }
+
+    fn validate(state: &[u64]) -> Result<(), String> {
+        if state.iter().any(|value| value % 2 == 0) {
+            return Err(String::from("Multiplicative lags need odd state values"));
+        }
+        Ok(())
+    }
+
+    fn prepare(word: u64) -> u64 {
+        word | 1
+    }
+}
+
+/// Lagged Fibonacci generator over 64 bit words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaggedFibo<Op: LagOperation = Additive> {
+    /// Ring buffer of the last `long_lag` values, oldest at `position`
+    state: Vec<u64>,
+    short_lag: usize,
+    position: usize,
+    operation: PhantomData<Op>,
+}
+
+impl<Op: LagOperation> LaggedFibo<Op> {
+    /// Starts from explicit history, oldest value first. `state.len()` is the
+    /// long lag.
+    pub fn from_state(short_lag: usize, state: Vec<u64>) -> Result<Self, String> {
+        if short_lag == 0 || short_lag >= state.len() {
+            return Err(String::from("Lags must satisfy 0 < short < long"));
+        }
+        Op::validate(&state)?;
+
+        Ok(Self {
+            state,
+            short_lag,
+            position: 0,
+            operation: PhantomData,
+        })
+    }
+
+    /// Deterministic state for any lags, expanded from `seed` with SplitMix64.
+    pub fn with_lags(short_lag: usize, long_lag: usize, seed: u64) -> Result<Self, String> {
+        let mut splitmix = seed;
+        let mut state: Vec<u64> = (0..long_lag)
+            .map(|_| Op::prepare(split_mix(&mut splitmix)))
+            .collect();
+        if let Some(first) = state.first_mut() {
+            *first |= 1;
+        }
+
+        Self::from_state(short_lag, state)
+    }
+
+    pub fn lags(&self) -> (usize, usize) {
+        (self.short_lag, self.state.len())
+    }
+}
+
+impl<Op: LagOperation> RngCore for LaggedFibo<Op> {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let long_lag = self.state.len();
+        let short = self.state[(self.position + long_lag - self.short_lag) % long_lag];
+        let next = Op::combine(short, self.state[self.position]);
+
+        self.state[self.position] = next;
+        self.position = (self.position + 1) % long_lag;
+        next
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// Seeds the (24, 55) generator. Each 8 byte word of the seed drives its own
+/// SplitMix64 stream, so all 256 bits reach the state.
+impl<Op: LagOperation> SeedableRng for LaggedFibo<Op> {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut streams: Vec<u64> = seed
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("8 byte chunk")))
+            .collect();
+
+        let mut state: Vec<u64> = (0..LONG_LAG)
+            .map(|i| Op::prepare(split_mix(&mut streams[i % 4])))
+            .collect();
+        state[0] |= 1;
+
+        Self::from_state(SHORT_LAG, state).expect("Classic lags with an odd value are valid")
+    }
+}
+
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(GOLDEN_GAMMA);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use num_bigint::BigInt;
+    use num_traits::{One, ToPrimitive, Zero};
+    use test_case::test_case;
+
+    // Regression snapshots taken from this implementation: they catch changes
+    // to the seeding or the recurrence, not mistakes present from the start.
+    // `test_matches_direct_recurrence` checks the recurrence itself.
+    const ADDITIVE_KNOWN: [u64; 4] = [
+        1_891_964_685_979_405_121,
+        4_900_180_205_554_454_800,
+        13_200_201_636_595_999_011,
+        10_309_704_476_129_784_987,
+    ];
+    const MULTIPLICATIVE_KNOWN: [u64; 4] = [
+        16_367_678_880_918_722_993,
+        17_431_256_718_217_655_455,
+        11_942_589_575_019_146_435,
+        3_009_039_802_664_140_851,
+    ];
+    const ADDITIVE_10K: u64 = 3_721_839_357_428_743_513;
+
+    fn seed() -> [u8; 32] {
+        std::array::from_fn(|i| i as u8)
+    }
+
+    #[test]
+    fn test_shortest_lags_give_fibonacci() {
+        let mut rng = LaggedFibo::<Additive>::from_state(1, vec![0, 1]).unwrap();
+        let modulus = BigInt::one() << 64;
+
+        for n in 2..200 {
+            let expected: BigInt = nth_term(&(BigInt::zero(), BigInt::one()), n) % &modulus;
+            assert_eq!(rng.next_u64(), expected.to_u64().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_shortest_lags() {
+        // 3 · 5 = 15, 5 · 15 = 75, ...
+        let mut rng = LaggedFibo::<Multiplicative>::from_state(1, vec![3, 5]).unwrap();
+        let values: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        assert_eq!(values, [15, 75, 1_125, 84_375]);
+    }
+
+    /// xₙ from the full history instead of the ring buffer.
+    fn direct_recurrence<Op: LagOperation>(
+        short_lag: usize, mut history: Vec<u64>, count: usize,
+    ) -> Vec<u64> {
+        let long_lag = history.len();
+        for n in long_lag..long_lag + count {
+            history.push(Op::combine(history[n - short_lag], history[n - long_lag]));
+        }
+        history.split_off(long_lag)
+    }
+
+    #[test]
+    fn test_matches_direct_recurrence() {
+        let mut splitmix = 0;
+        let state: Vec<u64> = (0..LONG_LAG).map(|_| split_mix(&mut splitmix) | 1).collect();
+
+        let mut additive = LaggedFibo::<Additive>::from_state(SHORT_LAG, state.clone()).unwrap();
+        let values: Vec<u64> = (0..500).map(|_| additive.next_u64()).collect();
+        assert_eq!(values, direct_recurrence::<Additive>(SHORT_LAG, state.clone(), 500));
+
+        let mut multiplicative =
+            LaggedFibo::<Multiplicative>::from_state(SHORT_LAG, state.clone()).unwrap();
+        let values: Vec<u64> = (0..500).map(|_| multiplicative.next_u64()).collect();
+        assert_eq!(values, direct_recurrence::<Multiplicative>(SHORT_LAG, state, 500));
+    }
+
+    #[test]
+    fn test_additive_known_answers() {
+        let mut rng = LaggedFibo::<Additive>::from_seed(seed());
+        let values: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        assert_eq!(values, ADDITIVE_KNOWN);
+    }
+
+    #[test]
+    fn test_multiplicative_known_answers() {
+        let mut rng = LaggedFibo::<Multiplicative>::from_seed(seed());
+        let values: Vec<u64> = (0..4).map(|_| rng.next_u64()).collect();
+        assert_eq!(values, MULTIPLICATIVE_KNOWN);
+        assert!(values.iter().all(|value| value % 2 == 1));
+    }
+
+    #[test]
+    fn test_known_answer_after_many_rounds() {
+        let mut rng = LaggedFibo::<Additive>::with_lags(SHORT_LAG, LONG_LAG, 42).unwrap();
+        let value = (0..10_000).map(|_| rng.next_u64()).last();
+        assert_eq!(value, Some(ADDITIVE_10K));
+    }
+
+    #[test]
+    fn test_reproducible_seeding() {
+        let mut first = LaggedFibo::<Additive>::seed_from_u64(7);
+        let mut second = LaggedFibo::<Additive>::seed_from_u64(7);
+        let mut other = LaggedFibo::<Additive>::seed_from_u64(8);
+
+        let (mut a, mut b, mut c) = ([0u8; 37], [0u8; 37], [0u8; 37]);
+        first.fill_bytes(&mut a);
+        second.fill_bytes(&mut b);
+        other.fill_bytes(&mut c);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(first.next_u32(), second.next_u32());
+    }
+
+    #[test_case(0, vec![1, 2, 3], "Lags must satisfy 0 < short < long"; "zero lag")]
+    #[test_case(3, vec![1, 2, 3], "Lags must satisfy 0 < short < long"; "equal lags")]
+    #[test_case(1, vec![2, 4, 6], "Additive lags need at least one odd state value"; "all even")]
+    fn test_invalid_additive(short_lag: usize, state: Vec<u64>, expected: &str) {
+        let result = LaggedFibo::<Additive>::from_state(short_lag, state);
+        assert_eq!(result, Err(expected.to_string()));
+    }
+
+    #[test]
+    fn test_invalid_multiplicative() {
+        let result = LaggedFibo::<Multiplicative>::from_state(1, vec![3, 4]);
+        assert_eq!(
+            result,
+            Err(String::from("Multiplicative lags need odd state values"))
+        );
+    }
+}
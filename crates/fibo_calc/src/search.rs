@@ -0,0 +1,29 @@
+/// Gives up after this many indices unless told otherwise.
+pub const DEFAULT_INDEX_LIMIT: usize = 100_000;
+
+/// Open-ended search for the first `count` terms that pass the filters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirstMatches {
+    pub count: usize,
+    /// First index scanned
+    pub start: usize,
+    /// Scanning stops before this index even with fewer matches
+    pub index_limit: usize,
+}
+
+impl FirstMatches {
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            start: 0,
+            index_limit: DEFAULT_INDEX_LIMIT,
+        }
+    }
+}
+
+/// Progress of a search, which has no known total to report a percentage of.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchProgress {
+    pub matches: usize,
+    pub scanned: usize,
+}
@@ -0,0 +1,268 @@
+use crate::batch::TermWalker;
+use num_bigint::BigInt;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionPart {
+    Stepped { range: Range<usize>, step: usize },
+    Indices(Vec<usize>),
+}
+
+impl SelectionPart {
+    fn iter(&self) -> Box<dyn Iterator<Item = usize> + Send + '_> {
+        match self {
+            SelectionPart::Stepped { range, step } => Box::new(range.clone().step_by(*step)),
+            SelectionPart::Indices(indices) => Box::new(indices.iter().copied()),
+        }
+    }
+}
+
+/// Union of stepped ranges and explicit index lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexSelection {
+    parts: Vec<SelectionPart>,
+}
+
+impl IndexSelection {
+    pub fn range(range: Range<usize>) -> Self {
+        Self::stepped(range, 1)
+    }
+
+    pub fn stepped(range: Range<usize>, step: usize) -> Self {
+        Self {
+            parts: vec![SelectionPart::Stepped {
+                range,
+                step: step.max(1),
+            }],
+        }
+    }
+
+    pub fn indices(indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut indices: Vec<usize> = indices.into_iter().collect();
+        indices.sort_unstable();
+        indices.dedup();
+
+        Self {
+            parts: vec![SelectionPart::Indices(indices)],
+        }
+    }
+
+    pub fn union(mut self, other: IndexSelection) -> Self {
+        self.parts.extend(other.parts);
+        self
+    }
+
+    pub fn parts(&self) -> &[SelectionPart] {
+        &self.parts
+    }
+
+    /// The contiguous range, if this selection is exactly one.
+    pub fn as_range(&self) -> Option<Range<usize>> {
+        match self.parts.as_slice() {
+            [SelectionPart::Stepped { range, step: 1 }] => Some(range.clone()),
+            _ => None,
+        }
+    }
+
+    /// Selected indices in ascending order without duplicates.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut parts: Vec<_> = self.parts.iter().map(SelectionPart::iter).collect();
+        let mut heap: BinaryHeap<_> = parts
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(i, part)| part.next().map(|index| Reverse((index, i))))
+            .collect();
+        let mut last = None;
+
+        std::iter::from_fn(move || {
+            while let Some(Reverse((index, i))) = heap.pop() {
+                if let Some(next) = parts[i].next() {
+                    heap.push(Reverse((next, i)));
+                }
+                if last != Some(index) {
+                    last = Some(index);
+                    return Some(index);
+                }
+            }
+            None
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+}
+
+impl From<Range<usize>> for IndexSelection {
+    fn from(range: Range<usize>) -> Self {
+        Self::range(range)
+    }
+}
+
+/// Parses comma separated parts: `7`, `0..1000` or `0..1000 step 7`.
+impl FromStr for IndexSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut selection = IndexSelection::default();
+        let mut single = Vec::new();
+
+        for part in s.split(',').map(str::trim) {
+            if part.is_empty() {
+                return Err(format!("Empty selection part in '{}'", s));
+            }
+
+            let Some((start, rest)) = part.split_once("..") else {
+                single.push(parse_index(part)?);
+                continue;
+            };
+
+            let (end, step) = match rest.split_once("step") {
+                Some((end, step)) => (end, parse_index(step)?),
+                None => (rest, 1),
+            };
+            if step == 0 {
+                return Err(String::from("Step must be > 0"));
+            }
+
+            let (start, end) = (parse_index(start)?, parse_index(end)?);
+            if end <= start {
+                return Err(String::from("Range end must be > start"));
+            }
+            selection = selection.union(IndexSelection::stepped(start..end, step));
+        }
+
+        if !single.is_empty() {
+            selection = selection.union(IndexSelection::indices(single));
+        }
+
+        Ok(selection)
+    }
+}
+
+impl Display for IndexSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                SelectionPart::Stepped { range, step: 1 } => format!("{:?}", range),
+                SelectionPart::Stepped { range, step } => format!("{:?} step {}", range, step),
+                SelectionPart::Indices(indices) => indices
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+fn parse_index(s: &str) -> Result<usize, String> {
+    s.trim()
+        .parse()
+        .map_err(|_| format!("Invalid index '{}'", s.trim()))
+}
+
+/// Terms at the indices of a selection, walked by `TermWalker`.
+pub(crate) struct SelectedTerms<It: Iterator<Item = usize>> {
+    walker: TermWalker,
+    indices: It,
+}
+
+impl<It: Iterator<Item = usize>> SelectedTerms<It> {
+    pub(crate) fn new(start_nums: (BigInt, BigInt), indices: It) -> Self {
+        Self {
+            walker: TermWalker::new(start_nums),
+            indices,
+        }
+    }
+}
+
+impl<It: Iterator<Item = usize>> Iterator for SelectedTerms<It> {
+    type Item = (usize, BigInt);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some((index, self.walker.term(&BigInt::from(index))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use num_traits::{One, Zero};
+    use test_case::test_case;
+
+    fn classic(n: usize) -> BigInt {
+        nth_term(&(BigInt::zero(), BigInt::one()), n)
+    }
+
+    #[test_case("0..10 step 3", &[0, 3, 6, 9]; "stepped")]
+    #[test_case("5, 10, 100..103", &[5, 10, 100, 101, 102]; "mixed")]
+    #[test_case("0..6 step 2, 3..7 step 2", &[0, 2, 3, 4, 5]; "interleaved")]
+    #[test_case("4, 2, 4, 1..3", &[1, 2, 4]; "duplicates")]
+    fn test_parse_and_iter(input: &str, expected: &[usize]) {
+        let selection: IndexSelection = input.parse().unwrap();
+        assert_eq!(selection.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(selection.len(), expected.len());
+    }
+
+    #[test_case("", "Empty selection part in ''"; "empty")]
+    #[test_case("5..2", "Range end must be > start"; "reversed")]
+    #[test_case("0..10 step 0", "Step must be > 0"; "zero step")]
+    #[test_case("a..3", "Invalid index 'a'"; "invalid")]
+    fn test_parse_errors(input: &str, expected: &str) {
+        assert_eq!(input.parse::<IndexSelection>(), Err(expected.to_string()));
+    }
+
+    #[test]
+    fn test_as_range() {
+        assert_eq!(IndexSelection::range(2..5).as_range(), Some(2..5));
+        assert_eq!(IndexSelection::stepped(2..5, 2).as_range(), None);
+        assert_eq!(IndexSelection::indices([1, 2]).as_range(), None);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let selection: IndexSelection = "0..1000 step 7, 5, 10".parse().unwrap();
+        assert_eq!(selection.to_string(), "0..1000 step 7, 5, 10");
+        assert_eq!(
+            selection.to_string().parse::<IndexSelection>(),
+            Ok(selection)
+        );
+    }
+
+    #[test]
+    fn test_selected_terms_match_direct() {
+        let selection: IndexSelection = "0..3, 7, 9, 40..100 step 13, 500".parse().unwrap();
+        let terms: Vec<(usize, BigInt)> =
+            SelectedTerms::new((BigInt::zero(), BigInt::one()), selection.iter()).collect();
+        let expected: Vec<(usize, BigInt)> = selection.iter().map(|i| (i, classic(i))).collect();
+        assert_eq!(terms, expected);
+    }
+
+    #[test]
+    fn test_selected_terms_custom_seeds() {
+        let start_nums = (BigInt::from(2), BigInt::from(1));
+        let terms: Vec<BigInt> =
+            SelectedTerms::new(start_nums.clone(), [1, 20, 21, 60].into_iter())
+                .map(|(_, value)| value)
+                .collect();
+        let expected: Vec<BigInt> = [1, 20, 21, 60]
+            .iter()
+            .map(|&i| nth_term(&start_nums, i))
+            .collect();
+        assert_eq!(terms, expected);
+    }
+}
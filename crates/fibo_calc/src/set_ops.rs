@@ -0,0 +1,215 @@
+use crate::builder::{FiboBuilder, FilterFn};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SetOperation {
+    #[default]
+    Intersection,
+    /// Values of the first sequence missing from the second
+    Difference,
+    Union,
+}
+
+impl Display for SetOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetOperation::Intersection => write!(f, "∩"),
+            SetOperation::Difference => write!(f, "∖"),
+            SetOperation::Union => write!(f, "∪"),
+        }
+    }
+}
+
+/// Distinct values with |x| ≤ `bound` that pass each builder's filters,
+/// combined by merge-walking both sorted value lists. Ranges and selections
+/// are ignored; the bound decides where each sequence stops.
+pub fn combine_sequences(
+    first: &FiboBuilder, second: &FiboBuilder, operation: SetOperation, bound: &BigInt,
+) -> Vec<BigInt> {
+    let first = sorted_values(first, bound);
+    let second = sorted_values(second, bound);
+    merge_walk(&first, &second, operation)
+}
+
+fn merge_walk(first: &[BigInt], second: &[BigInt], operation: SetOperation) -> Vec<BigInt> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < first.len() && j < second.len() {
+        match first[i].cmp(&second[j]) {
+            Ordering::Less => {
+                if operation != SetOperation::Intersection {
+                    result.push(first[i].clone());
+                }
+                i += 1;
+            }
+            Ordering::Greater => {
+                if operation == SetOperation::Union {
+                    result.push(second[j].clone());
+                }
+                j += 1;
+            }
+            Ordering::Equal => {
+                if operation != SetOperation::Difference {
+                    result.push(first[i].clone());
+                }
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    if operation != SetOperation::Intersection {
+        result.extend_from_slice(&first[i..]);
+    }
+    if operation == SetOperation::Union {
+        result.extend_from_slice(&second[j..]);
+    }
+
+    result
+}
+
+/// Once two consecutive terms share a sign, every later term has that sign
+/// and a growing magnitude; only the all-zero sequence never gets there. The
+/// terms before that point are sorted in, the monotone tail is already in
+/// order (reversed for a falling sequence).
+fn sorted_values(builder: &FiboBuilder, bound: &BigInt) -> Vec<BigInt> {
+    let (mut pre, mut post) = builder
+        .get_start_nums()
+        .unwrap_or_else(|| (BigInt::zero(), BigInt::one()));
+    let filters = builder.get_filters();
+
+    let mut prefix = Vec::new();
+    if pre.is_zero() && post.is_zero() {
+        push_bounded(&mut prefix, &pre, bound, filters);
+        return prefix;
+    }
+
+    while pre.sign() != post.sign() || pre.is_zero() {
+        push_bounded(&mut prefix, &pre, bound, filters);
+        let next = &pre + &post;
+        pre = std::mem::replace(&mut post, next);
+    }
+
+    // |pre| may still exceed |post|, the tail is monotone from `post` on
+    push_bounded(&mut prefix, &pre, bound, filters);
+    let next = &pre + &post;
+    pre = std::mem::replace(&mut post, next);
+
+    let falling = pre.is_negative();
+    let mut tail = Vec::new();
+    while pre.magnitude() <= bound.magnitude() {
+        push_bounded(&mut tail, &pre, bound, filters);
+        let next = &pre + &post;
+        pre = std::mem::replace(&mut post, next);
+    }
+    if falling {
+        tail.reverse();
+    }
+
+    prefix.sort_unstable();
+    let mut values = merge_walk(&prefix, &tail, SetOperation::Union);
+    values.dedup();
+    values
+}
+
+fn push_bounded(values: &mut Vec<BigInt>, value: &BigInt, bound: &BigInt, filters: &[FilterFn]) {
+    if value.magnitude() <= bound.magnitude() && filters.iter().all(|f| f(value)) {
+        values.push(value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn builder(a: i64, b: i64) -> FiboBuilder {
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some((BigInt::from(a), BigInt::from(b))));
+        builder
+    }
+
+    fn ints(values: &[i64]) -> Vec<BigInt> {
+        values.iter().copied().map(BigInt::from).collect()
+    }
+
+    #[test_case(SetOperation::Intersection, &[1, 2, 3]; "intersection")]
+    #[test_case(SetOperation::Difference, &[0, 5, 8, 13, 21, 34, 55, 89]; "difference")]
+    #[test_case(SetOperation::Union, &[0, 1, 2, 3, 4, 5, 7, 8, 11, 13, 18, 21, 29, 34, 47, 55, 76, 89]; "union")]
+    fn test_fibonacci_and_lucas(operation: SetOperation, expected: &[i64]) {
+        let result = combine_sequences(
+            &builder(0, 1),
+            &builder(2, 1),
+            operation,
+            &BigInt::from(100),
+        );
+        assert_eq!(result, ints(expected));
+    }
+
+    #[test]
+    fn test_large_bound_intersection() {
+        // Only 1, 2 and 3 are both Fibonacci and Lucas numbers
+        let bound = BigInt::from(10).pow(500);
+        let result = combine_sequences(
+            &builder(0, 1),
+            &builder(2, 1),
+            SetOperation::Intersection,
+            &bound,
+        );
+        assert_eq!(result, ints(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_shifted_sequence_is_subset() {
+        // Seeds (3, 5) are the classic sequence from F(4) on
+        let bound = BigInt::from(10).pow(50);
+        let result = combine_sequences(
+            &builder(3, 5),
+            &builder(0, 1),
+            SetOperation::Difference,
+            &bound,
+        );
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_non_monotone_and_negative_seeds() {
+        // 5, -3, 2, -1, 1, 0, 1, 1, 2, 3, ...
+        assert_eq!(
+            sorted_values(&builder(5, -3), &BigInt::from(5)),
+            ints(&[-3, -1, 0, 1, 2, 3, 5])
+        );
+        // -1, -1, -2, -3, -5, ...
+        assert_eq!(
+            sorted_values(&builder(-1, -1), &BigInt::from(6)),
+            ints(&[-5, -3, -2, -1])
+        );
+        assert_eq!(sorted_values(&builder(0, 0), &BigInt::from(6)), ints(&[0]));
+    }
+
+    #[test]
+    fn test_large_first_seed() {
+        // 50, 1, 51, 52, ... where 50 > 1 share a sign but are out of order
+        assert_eq!(
+            sorted_values(&builder(50, 1), &BigInt::from(60)),
+            ints(&[1, 50, 51, 52])
+        );
+    }
+
+    #[test]
+    fn test_filters_apply_per_builder() {
+        let mut even = builder(0, 1);
+        even.add_filter(|n| n % 2u8 == BigInt::zero());
+        let result = combine_sequences(
+            &even,
+            &builder(2, 1),
+            SetOperation::Intersection,
+            &BigInt::from(1000),
+        );
+        assert_eq!(result, ints(&[2]));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::radix::decimal_string;
+use num_bigint::{BigInt, BigUint, Sign};
+use std::io::{Read, Write};
+
+/// First bytes of a file written by `BinarySink`.
+pub const BINARY_MAGIC: &[u8; 4] = b"FIBL";
+
+/// Receives the terms of a range or selection one at a time, in order, so
+/// they never need to be held in memory together.
+pub trait ResultSink: Send {
+    fn write(&mut self, index: usize, value: &BigInt) -> Result<(), String>;
+
+    /// Flushes whatever is buffered after the last term.
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+pub type BoxedSink = Box<dyn ResultSink>;
+
+/// Extra CSV columns computed from each value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvColumn {
+    DigitCount,
+    DigitSum,
+    BitLength,
+    Parity,
+}
+
+impl CsvColumn {
+    fn header(&self) -> &'static str {
+        match self {
+            CsvColumn::DigitCount => "digits",
+            CsvColumn::DigitSum => "digit_sum",
+            CsvColumn::BitLength => "bits",
+            CsvColumn::Parity => "parity",
+        }
+    }
+
+    /// `digits` is the decimal magnitude of `value`.
+    fn cell(&self, value: &BigInt, digits: &str) -> String {
+        match self {
+            CsvColumn::DigitCount => digits.len().to_string(),
+            CsvColumn::DigitSum => digits
+                .bytes()
+                .map(|digit| u64::from(digit - b'0'))
+                .sum::<u64>()
+                .to_string(),
+            CsvColumn::BitLength => value.bits().to_string(),
+            CsvColumn::Parity if value.bit(0) => String::from("odd"),
+            CsvColumn::Parity => String::from("even"),
+        }
+    }
+}
+
+/// `index,value` rows after a header, plus any derived columns.
+pub struct CsvSink<W: Write + Send> {
+    writer: W,
+    columns: Vec<CsvColumn>,
+    header_written: bool,
+}
+
+impl<W: Write + Send> CsvSink<W> {
+    pub fn new(writer: W, columns: Vec<CsvColumn>) -> Self {
+        Self {
+            writer,
+            columns,
+            header_written: false,
+        }
+    }
+
+    fn write_header(&mut self) -> Result<(), String> {
+        self.header_written = true;
+        let mut header = String::from("index,value");
+        for column in &self.columns {
+            header.push(',');
+            header.push_str(column.header());
+        }
+        writeln!(self.writer, "{}", header).map_err(write_error)
+    }
+}
+
+impl<W: Write + Send> ResultSink for CsvSink<W> {
+    fn write(&mut self, index: usize, value: &BigInt) -> Result<(), String> {
+        if !self.header_written {
+            self.write_header()?;
+        }
+
+        let text = decimal_string(value);
+        let digits = text.trim_start_matches('-');
+        let mut row = format!("{},{}", index, text);
+        for column in &self.columns {
+            row.push(',');
+            row.push_str(&column.cell(value, digits));
+        }
+        writeln!(self.writer, "{}", row).map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        if !self.header_written {
+            self.write_header()?;
+        }
+        self.writer.flush().map_err(write_error)
+    }
+}
+
+/// One `{"index":…,"value":"…"}` object per line. Values are strings so
+/// readers keep every digit.
+pub struct NdjsonSink<W: Write + Send> {
+    writer: W,
+}
+
+impl<W: Write + Send> NdjsonSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write + Send> ResultSink for NdjsonSink<W> {
+    fn write(&mut self, index: usize, value: &BigInt) -> Result<(), String> {
+        writeln!(
+            self.writer,
+            "{{\"index\":{},\"value\":\"{}\"}}",
+            index,
+            decimal_string(value)
+        )
+        .map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.writer.flush().map_err(write_error)
+    }
+}
+
+/// One decimal value per line.
+pub struct TextSink<W: Write + Send> {
+    writer: W,
+}
+
+impl<W: Write + Send> TextSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write + Send> ResultSink for TextSink<W> {
+    fn write(&mut self, _index: usize, value: &BigInt) -> Result<(), String> {
+        writeln!(self.writer, "{}", decimal_string(value)).map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.writer.flush().map_err(write_error)
+    }
+}
+
+/// `BINARY_MAGIC`, then per term the index as u64, a sign byte (−1, 0, 1),
+/// the limb count as u64 and the magnitude's u64 limbs, all little endian.
+/// Skips decimal conversion entirely; read back with `BinaryReader`.
+pub struct BinarySink<W: Write + Send> {
+    writer: W,
+    magic_written: bool,
+}
+
+impl<W: Write + Send> BinarySink<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            magic_written: false,
+        }
+    }
+
+    fn write_magic(&mut self) -> Result<(), String> {
+        self.magic_written = true;
+        self.writer.write_all(BINARY_MAGIC).map_err(write_error)
+    }
+}
+
+impl<W: Write + Send> ResultSink for BinarySink<W> {
+    fn write(&mut self, index: usize, value: &BigInt) -> Result<(), String> {
+        if !self.magic_written {
+            self.write_magic()?;
+        }
+
+        let (sign, limbs) = value.to_u64_digits();
+        let sign: i8 = match sign {
+            Sign::Minus => -1,
+            Sign::NoSign => 0,
+            Sign::Plus => 1,
+        };
+
+        let mut record = Vec::with_capacity(17 + 8 * limbs.len());
+        record.extend_from_slice(&(index as u64).to_le_bytes());
+        record.extend_from_slice(&sign.to_le_bytes());
+        record.extend_from_slice(&(limbs.len() as u64).to_le_bytes());
+        for limb in limbs {
+            record.extend_from_slice(&limb.to_le_bytes());
+        }
+        self.writer.write_all(&record).map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        if !self.magic_written {
+            self.write_magic()?;
+        }
+        self.writer.flush().map_err(write_error)
+    }
+}
+
+/// (index, value) records of a `BinarySink` file in order.
+pub struct BinaryReader<R: Read> {
+    reader: R,
+    magic_checked: bool,
+    failed: bool,
+}
+
+impl<R: Read> BinaryReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            magic_checked: false,
+            failed: false,
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Option<(usize, BigInt)>, String> {
+        if !self.magic_checked {
+            self.magic_checked = true;
+            let mut magic = [0; 4];
+            self.reader.read_exact(&mut magic).map_err(read_error)?;
+            if &magic != BINARY_MAGIC {
+                return Err(String::from("Not a binary results file"));
+            }
+        }
+
+        let mut index = [0; 8];
+        match self.reader.read(&mut index[..1]).map_err(read_error)? {
+            0 => return Ok(None),
+            _ => self
+                .reader
+                .read_exact(&mut index[1..])
+                .map_err(read_error)?,
+        }
+
+        let mut header = [0; 9];
+        self.reader.read_exact(&mut header).map_err(read_error)?;
+        let sign = match header[0] as i8 {
+            -1 => Sign::Minus,
+            0 => Sign::NoSign,
+            1 => Sign::Plus,
+            other => return Err(format!("Invalid sign byte {}", other)),
+        };
+        let count = u64::from_le_bytes(header[1..].try_into().unwrap_or_default());
+
+        // The count is untrusted: grow the buffer only as bytes actually arrive
+        let len = count
+            .checked_mul(8)
+            .ok_or_else(|| format!("Invalid limb count {}", count))?;
+        let mut bytes = Vec::new();
+        let read = (&mut self.reader)
+            .take(len)
+            .read_to_end(&mut bytes)
+            .map_err(read_error)?;
+        if read as u64 != len {
+            return Err(format!(
+                "Cannot read results: record needs {} limbs but the file ends",
+                count
+            ));
+        }
+        let limbs: Vec<u64> = bytes
+            .chunks_exact(8)
+            .map(|limb| u64::from_le_bytes(limb.try_into().unwrap_or_default()))
+            .collect();
+
+        let value = BigInt::from_biguint(sign, BigUint::new(to_u32_digits(&limbs)));
+        Ok(Some((u64::from_le_bytes(index) as usize, value)))
+    }
+}
+
+impl<R: Read> Iterator for BinaryReader<R> {
+    type Item = Result<(usize, BigInt), String>;
+
+    /// Stops after the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let record = self.read_record();
+        self.failed = record.is_err();
+        record.transpose()
+    }
+}
+
+fn to_u32_digits(limbs: &[u64]) -> Vec<u32> {
+    limbs
+        .iter()
+        .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+        .collect()
+}
+
+fn write_error(e: std::io::Error) -> String {
+    format!("Cannot write results: {}", e)
+}
+
+fn read_error(e: std::io::Error) -> String {
+    format!("Cannot read results: {}", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn terms() -> Vec<(usize, BigInt)> {
+        let start_nums = (BigInt::from(3), BigInt::from(-7));
+        (0..6).map(|n| (n, nth_term(&start_nums, n))).collect()
+    }
+
+    fn write_all(sink: &mut dyn ResultSink, terms: &[(usize, BigInt)]) {
+        for (index, value) in terms {
+            sink.write(*index, value).unwrap();
+        }
+        sink.finish().unwrap();
+    }
+
+    #[test_case(vec![], "index,value\n0,3\n1,-7\n2,-4\n"; "plain")]
+    #[test_case(
+        vec![CsvColumn::DigitCount, CsvColumn::DigitSum, CsvColumn::BitLength, CsvColumn::Parity],
+        "index,value,digits,digit_sum,bits,parity\n\
+         0,3,1,3,2,odd\n1,-7,1,7,3,odd\n2,-4,1,4,3,even\n";
+        "derived columns"
+    )]
+    fn test_csv_sink(columns: Vec<CsvColumn>, expected: &str) {
+        let mut output = Vec::new();
+        write_all(&mut CsvSink::new(&mut output, columns), &terms()[..3]);
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_csv_sink_without_terms_writes_header() {
+        let mut output = Vec::new();
+        write_all(&mut CsvSink::new(&mut output, vec![CsvColumn::Parity]), &[]);
+        assert_eq!(String::from_utf8(output).unwrap(), "index,value,parity\n");
+    }
+
+    #[test]
+    fn test_ndjson_and_text_sinks() {
+        let mut json = Vec::new();
+        write_all(&mut NdjsonSink::new(&mut json), &terms()[3..5]);
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"index\":3,\"value\":\"-11\"}\n{\"index\":4,\"value\":\"-15\"}\n"
+        );
+
+        let mut text = Vec::new();
+        write_all(&mut TextSink::new(&mut text), &terms()[3..5]);
+        assert_eq!(String::from_utf8(text).unwrap(), "-11\n-15\n");
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut terms = terms();
+        terms.push((500, nth_term(&(BigInt::from(0), BigInt::from(1)), 500)));
+        terms.push((501, BigInt::ZERO));
+
+        let mut output = Vec::new();
+        write_all(&mut BinarySink::new(&mut output), &terms);
+        assert_eq!(&output[..4], BINARY_MAGIC);
+
+        let read: Result<Vec<_>, _> = BinaryReader::new(output.as_slice()).collect();
+        assert_eq!(read.unwrap(), terms);
+    }
+
+    #[test]
+    fn test_binary_reader_errors() {
+        let mut reader = BinaryReader::new(&b"CSV!"[..]);
+        assert_eq!(
+            reader.next(),
+            Some(Err(String::from("Not a binary results file")))
+        );
+
+        let mut output = Vec::new();
+        write_all(&mut BinarySink::new(&mut output), &terms()[..1]);
+        output.pop();
+        let mut reader = BinaryReader::new(output.as_slice());
+        assert!(matches!(reader.next(), Some(Err(_))));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test_case(u64::MAX; "overflowing count")]
+    #[test_case(1 << 40; "count past the end")]
+    fn test_binary_reader_corrupt_count(count: u64) {
+        let mut output = Vec::new();
+        write_all(&mut BinarySink::new(&mut output), &terms()[..1]);
+        // The count follows the magic, the index and the sign byte
+        output[13..21].copy_from_slice(&count.to_le_bytes());
+
+        let mut reader = BinaryReader::new(output.as_slice());
+        assert!(matches!(reader.next(), Some(Err(_))));
+        assert_eq!(reader.next(), None);
+    }
+}
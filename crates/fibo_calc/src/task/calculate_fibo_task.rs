@@ -1,80 +1,520 @@
+use crate::aggregate::{self, AggregateMode, FiboAggregate};
 use crate::builder::FilterFn;
 use crate::calculator::ImplementationFibo;
+use crate::checkpoint::{self, Checkpoint, CheckpointJob, RangeCheckpointer};
+use crate::polynomial::{self, FiboPolynomial, PolynomialKind};
+use crate::search::{FirstMatches, SearchProgress};
+use crate::selection::{IndexSelection, SelectedTerms};
+use crate::sink::{BoxedSink, ResultSink};
+use crate::transform::{Transform, TransformStage, TransformStream};
+use crate::verify::{Verifier, VerifyStream};
 use crate::{FiboBuilder, FiboTaskResult, task};
 use num_bigint::BigInt;
+use num_traits::{One, Zero};
 use rayon::prelude::*;
+use std::ops::Range;
 
 const CHUNK_SIZE: usize = 1000;
 
 
-pub async fn calculate_fibo_task<I: ImplementationFibo>(
-    builder: FiboBuilder, sender: task::FiboTaskSender,
+/// Blocks on the BigInt work and streams messages through `sender`, so it
+/// belongs on a blocking thread rather than an async worker.
+pub fn calculate_fibo_task<I: ImplementationFibo>(
+    mut builder: FiboBuilder, sender: task::FiboTaskSender,
 ) {
+    if let Some(sink) = builder.take_sink() {
+        let written = sink_support(&builder)
+            .and_then(|_| write_to_sink::<I>(&builder, &sender, sink));
+        let _ = sender.send(FiboTaskResult::Written(written));
+        return;
+    }
+
+    if let Some(kind) = builder.get_polynomials() {
+        let polynomials =
+            polynomial_support(&builder).map(|_| calculate_polynomials(&builder, &sender, kind));
+        let _ = sender.send(FiboTaskResult::Polynomials(polynomials));
+        return;
+    }
+
     if builder.is_none_filter() {
-        let _ = sender.send(FiboTaskResult::Result(vec![]));
+        send_final_result(&builder, &sender, Vec::new());
+        return;
+    }
+
+    if let (Some(start_nums), Some(search)) = (builder.get_start_nums(), builder.get_search()) {
+        let mut verify = verify_stream(&builder);
+        let found = search_with_progress::<I>(&sender, &builder, start_nums, &search, &mut verify);
+        send_stream_verification(&sender, verify);
+        let after = builder.get_transforms_at(TransformStage::AfterFilters);
+        let mut progress = Progress::new(&sender, after.len());
+        let found = apply_transforms_with_progress(&mut progress, found, &after);
+        send_final_result(&builder, &sender, found);
+        return;
+    }
+
+    if let (Some(start_nums), Some(value_range)) =
+        (builder.get_start_nums(), builder.get_value_range())
+    {
+        let selection = value_range.indices(&start_nums);
+        calculate_selection(&builder, &sender, start_nums, selection);
+        return;
+    }
+
+    if let (Some(start_nums), Some(selection)) =
+        (builder.get_start_nums(), builder.get_index_selection())
+    {
+        calculate_selection(&builder, &sender, start_nums, selection);
         return;
     }
 
     let (start_nums, range) = match (builder.get_start_nums(), builder.get_range_by_id()) {
         (Some((n1, n2)), Some(range)) if range.start <= range.end => ((n1, n2), range),
         _ => {
-            let _ = sender.send(FiboTaskResult::Result(vec![]));
+            send_final_result(&builder, &sender, Vec::new());
             return;
         }
     };
 
     let total_items = range.end.saturating_sub(range.start);
     if total_items == 0 {
-        let _ = sender.send(FiboTaskResult::Result(vec![]));
+        send_final_result(&builder, &sender, Vec::new());
+        return;
+    }
+
+    if let Some(mode) = builder
+        .get_aggregate()
+        .filter(|mode| !mode.keep_values && builder.get_transforms().is_empty())
+    {
+        let mut verify = verify_stream(&builder);
+        let filters = builder.get_filters();
+        let result =
+            aggregate_with_progress::<I>(&sender, start_nums, range, filters, &mode, &mut verify);
+        send_stream_verification(&sender, verify);
+        let _ = sender.send(FiboTaskResult::Aggregate(result));
+        return;
+    }
+
+    let mut progress = Progress::for_pipeline(&sender, &builder);
+    let result = generate_range::<I>(&builder, &mut progress, start_nums, range.clone(), None);
+    send_verification(&builder, &sender, (range.start..).zip(&result));
+    let result = apply_pipeline(&mut progress, &builder, result);
+    send_final_result(&builder, &sender, result);
+}
+
+/// Continues a checkpointed job with the builder's filters, transforms and
+/// aggregate. `results` are the terms the checkpoint had already saved.
+pub fn resume_fibo_task<I: ImplementationFibo>(
+    mut builder: FiboBuilder, checkpoint: Checkpoint, results: Vec<BigInt>,
+    sender: task::FiboTaskSender,
+) {
+    builder.set_start_nums(Some(checkpoint.start_nums.clone()));
+
+    match checkpoint.job {
+        CheckpointJob::Range {
+            range,
+            pair,
+            offset,
+            ..
+        } => {
+            builder.set_range_by_id(Some(range.clone()));
+            let resumed = Some((results, pair, offset));
+            let start_nums = checkpoint.start_nums;
+            let mut progress = Progress::for_pipeline(&sender, &builder);
+            let result =
+                generate_range::<I>(&builder, &mut progress, start_nums, range.clone(), resumed);
+            send_verification(&builder, &sender, (range.start..).zip(&result));
+            let result = apply_pipeline(&mut progress, &builder, result);
+            send_final_result(&builder, &sender, result);
+        }
+        CheckpointJob::One { n, bits_left, pair } => {
+            calculate_one_task(builder, n, Some((bits_left, pair)), sender);
+        }
+    }
+}
+
+/// G(n − 1) as `calc_one_number` for n ≥ 1, checkpointed when the builder has a config
+/// and continuing from `resumed` = (bits_left, pair) if given.
+pub fn calculate_one_task(
+    builder: FiboBuilder, n: BigInt, resumed: Option<(u64, (BigInt, BigInt))>,
+    sender: task::FiboTaskSender,
+) {
+    let start_nums = builder
+        .get_start_nums()
+        .unwrap_or((BigInt::zero(), BigInt::one()));
+    let value = checkpoint::calc_one(
+        start_nums,
+        n,
+        builder.get_checkpoint().as_ref(),
+        resumed,
+        |done, total| send_progress(&sender, done as usize, total as usize),
+        |e| send_checkpoint_failure(&sender, e),
+    );
+    let _ = sender.send(FiboTaskResult::Result(vec![value]));
+}
+
+/// G(range.start), … G(range.end − 1), continuing after the terms, the pair
+/// (G(next − 2), G(next − 1)) and the results file offset of a resumed
+/// checkpoint. Saves checkpoints when the builder has a config.
+fn generate_range<I: ImplementationFibo>(
+    builder: &FiboBuilder, progress: &mut Progress, start_nums: (BigInt, BigInt),
+    range: Range<usize>, resumed: Option<(Vec<BigInt>, (BigInt, BigInt), u64)>,
+) -> Vec<BigInt> {
+    let total_items = range.len();
+    let (mut result, pair, offset) = match resumed {
+        Some((result, pair, offset)) => (result, Some(pair), offset),
+        None => (Vec::with_capacity(total_items), None, 0),
+    };
+    let mut checkpointer = builder.get_checkpoint().map(|config| {
+        RangeCheckpointer::new(config, start_nums.clone(), range.clone(), result.len(), offset)
+    });
+
+    let impl_fibo = match pair {
+        // The pair is two terms before the next one, as G(0) and G(1) are before G(2)
+        Some(pair) => I::new(Some(pair)),
+        None => {
+            if range.start == 0 {
+                result.push(start_nums.0.clone());
+                progress.report(result.len(), total_items);
+            }
+
+            if range.start <= 1 && range.end > 1 {
+                result.push(start_nums.1.clone());
+                progress.report(result.len(), total_items);
+            }
+
+            let mut impl_fibo = I::new(Some(start_nums));
+            impl_fibo.jump_to(range.start.max(2));
+            impl_fibo
+        }
+    };
+
+    for num in impl_fibo.take(total_items.saturating_sub(result.len())) {
+        result.push(num);
+        let processed = result.len();
+
+        if processed % 10 == 0 || processed == total_items {
+            progress.report(processed, total_items);
+            if let Some(checkpointer) = &mut checkpointer
+                && let Err(e) = checkpointer.maybe_save(&result)
+            {
+                send_checkpoint_failure(progress.sender, e);
+            }
+        }
+    }
+
+    if let Some(checkpointer) = checkpointer {
+        checkpointer.finish();
+    }
+    progress.finish_stage();
+    result
+}
+
+fn calculate_selection(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender, start_nums: (BigInt, BigInt),
+    selection: IndexSelection,
+) {
+    let total_items = selection.len();
+    if total_items == 0 {
+        send_final_result(builder, sender, Vec::new());
         return;
     }
 
+    let mut progress = Progress::for_pipeline(sender, builder);
+    let mut indices = Vec::with_capacity(total_items);
     let mut result = Vec::with_capacity(total_items);
-    let mut processed = 0;
+    for (index, num) in SelectedTerms::new(start_nums, selection.iter()) {
+        indices.push(index);
+        result.push(num);
 
-    if range.start == 0 {
-        result.push(start_nums.0.clone());
-        processed += 1;
-        send_progress(&sender, processed, total_items).await;
+        let processed = result.len();
+        if processed % 10 == 0 || processed == total_items {
+            progress.report(processed, total_items);
+        }
     }
+    progress.finish_stage();
+
+    send_verification(builder, sender, indices.into_iter().zip(&result));
+    let result = apply_pipeline(&mut progress, builder, result);
+    send_final_result(builder, sender, result);
+}
+
+/// Sinks take the terms as they are generated, so nothing that needs them
+/// all at once can run.
+fn sink_support(builder: &FiboBuilder) -> Result<(), String> {
+    let unsupported = [
+        (!builder.get_transforms().is_empty(), "transforms"),
+        (builder.get_aggregate().is_some(), "an aggregate"),
+        (builder.get_search().is_some(), "a search"),
+        (builder.get_checkpoint().is_some(), "a checkpoint"),
+        (builder.get_polynomials().is_some(), "polynomials"),
+    ];
+    reject_settings("A sink", &unsupported)
+}
+
+/// Polynomials are not numbers, so only the filters, which see Pₙ(1), apply.
+fn polynomial_support(builder: &FiboBuilder) -> Result<(), String> {
+    let unsupported = [
+        (!builder.get_transforms().is_empty(), "transforms"),
+        (builder.get_aggregate().is_some(), "an aggregate"),
+        (builder.get_search().is_some(), "a search"),
+        (builder.get_value_range().is_some(), "a value range"),
+        (builder.get_verify(), "verification"),
+        (builder.get_checkpoint().is_some(), "a checkpoint"),
+    ];
+    reject_settings("Polynomials", &unsupported)
+}
+
+fn reject_settings(what: &str, unsupported: &[(bool, &str)]) -> Result<(), String> {
+    match unsupported.iter().find(|(set, _)| *set) {
+        Some((_, setting)) => Err(format!("{} cannot be combined with {}", what, setting)),
+        None => Ok(()),
+    }
+}
+
+/// Polynomials of the range or selection whose value at x = 1 passes the
+/// filters.
+fn calculate_polynomials(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender, kind: PolynomialKind,
+) -> Vec<FiboPolynomial> {
+    let Some(selection) = builder.get_effective_selection() else {
+        return Vec::new();
+    };
+
+    let total_items = selection.len();
+    let filters = builder.get_filters();
+    let one = BigInt::one();
+    let mut result = Vec::new();
+    for (processed, polynomial) in polynomial::select_polynomials(kind, &selection).enumerate() {
+        let value = polynomial.evaluate(&one);
+        if filters.iter().all(|filter| filter(&value)) {
+            result.push(polynomial);
+        }
 
-    if range.start <= 1 && range.end > 1 {
-        result.push(start_nums.1.clone());
-        processed += 1;
-        send_progress(&sender, processed, total_items).await;
+        let processed = processed + 1;
+        if processed % 10 == 0 || processed == total_items {
+            send_progress(sender, processed, total_items);
+        }
     }
+    result
+}
 
-    if range.end > 2 {
-        let impl_fibo = I::new(Some(start_nums));
-        let skip_count = if range.start > 2 { range.start - 2 } else { 0 };
-        let take_count = range.end - 2;
+/// Streams the filtered terms of the value range, selection or range into
+/// `sink` without keeping them, returning how many were written.
+fn write_to_sink<I: ImplementationFibo>(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender, mut sink: BoxedSink,
+) -> Result<usize, String> {
+    let Some(start_nums) = builder.get_start_nums() else {
+        return sink.finish().map(|_| 0);
+    };
+    let selection = match builder.get_value_range() {
+        Some(value_range) => Some(value_range.indices(&start_nums)),
+        None => builder.get_index_selection(),
+    };
 
-        for num in impl_fibo.skip(skip_count).take(take_count) {
-            result.push(num);
-            processed += 1;
+    let written = match (selection, builder.get_range_by_id()) {
+        (Some(selection), _) => {
+            let terms = SelectedTerms::new(start_nums, selection.iter());
+            stream_terms(builder, sender, sink.as_mut(), terms, selection.len())?
+        }
+        (None, Some(range)) => {
+            let total_items = range.len();
+            let terms = (range.start..).zip(terms_from::<I>(start_nums, range.start));
+            stream_terms(builder, sender, sink.as_mut(), terms.take(total_items), total_items)?
+        }
+        (None, None) => 0,
+    };
 
-            if processed % 10 == 0 || processed == total_items {
-                send_progress(&sender, processed, total_items).await;
-            }
+    sink.finish().map(|_| written)
+}
+
+fn stream_terms(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender, sink: &mut dyn ResultSink,
+    terms: impl Iterator<Item = (usize, BigInt)>, total_items: usize,
+) -> Result<usize, String> {
+    let filters = builder.get_filters();
+    let mut verify = verify_stream(builder);
+    let mut written = 0;
+
+    for (processed, (index, num)) in terms.enumerate().map(|(i, term)| (i + 1, term)) {
+        if let Some(verify) = verify.as_mut() {
+            verify.push(index, &num);
+        }
+        if filters.iter().all(|f| f(&num)) {
+            sink.write(index, &num)?;
+            written += 1;
+        }
+
+        if processed % CHUNK_SIZE == 0 || processed == total_items {
+            send_progress(sender, processed, total_items);
+        }
+    }
+
+    send_stream_verification(sender, verify);
+    Ok(written)
+}
+
+/// Checks the generated terms, before any transform, when the builder asks to.
+fn send_verification<'a>(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender,
+    terms: impl IntoIterator<Item = (usize, &'a BigInt)>,
+) {
+    if let (true, Some(start_nums)) = (builder.get_verify(), builder.get_start_nums()) {
+        let verifications = Verifier::new(start_nums).check(terms);
+        let _ = sender.send(FiboTaskResult::Verification(verifications));
+    }
+}
+
+/// Checks terms as they stream past when the builder asks to.
+fn verify_stream(builder: &FiboBuilder) -> Option<VerifyStream> {
+    match (builder.get_verify(), builder.get_start_nums()) {
+        (true, Some(start_nums)) => Some(VerifyStream::new(Verifier::new(start_nums))),
+        _ => None,
+    }
+}
+
+fn send_stream_verification(sender: &task::FiboTaskSender, verify: Option<VerifyStream>) {
+    if let Some(verify) = verify {
+        let _ = sender.send(FiboTaskResult::Verification(verify.finish()));
+    }
+}
+
+/// Streams terms from `search.start` and keeps those passing every filter,
+/// reporting matches and scanned indices every chunk and on each match.
+fn search_with_progress<I: ImplementationFibo>(
+    sender: &task::FiboTaskSender, builder: &FiboBuilder, start_nums: (BigInt, BigInt),
+    search: &FirstMatches, verify: &mut Option<VerifyStream>,
+) -> Vec<BigInt> {
+    let mut found = Vec::with_capacity(search.count);
+    let mut progress = SearchProgress::default();
+    if search.count == 0 {
+        let _ = sender.send(FiboTaskResult::Search(progress));
+        return found;
+    }
+
+    let numbers = terms_from::<I>(start_nums, search.start)
+        .take(search.index_limit.saturating_sub(search.start));
+    let filters = builder.get_filters();
+    let mut before: Vec<TransformStream> = builder
+        .get_transforms_at(TransformStage::BeforeFilters)
+        .into_iter()
+        .map(TransformStream::new)
+        .collect();
+
+    for (index, num) in (search.start..).zip(numbers) {
+        if let Some(verify) = verify.as_mut() {
+            verify.push(index, &num);
+        }
+        progress.scanned += 1;
+        let Some(num) = before.iter_mut().try_fold(num, |num, stream| stream.push(num)) else {
+            continue;
+        };
+        let is_match = filters.iter().all(|f| f(&num));
+        if is_match {
+            found.push(num);
+            progress.matches += 1;
+        }
+
+        if found.len() == search.count {
+            break;
+        }
+        if is_match || progress.scanned % CHUNK_SIZE == 0 {
+            let _ = sender.send(FiboTaskResult::Search(progress));
         }
     }
 
+    let _ = sender.send(FiboTaskResult::Search(progress));
+    found
+}
+
+/// Transforms before the filters, the filters, then transforms after them.
+fn apply_pipeline(
+    progress: &mut Progress, builder: &FiboBuilder, numbers: Vec<BigInt>,
+) -> Vec<BigInt> {
+    let before = builder.get_transforms_at(TransformStage::BeforeFilters);
+    let after = builder.get_transforms_at(TransformStage::AfterFilters);
+
     let filters = builder.get_filters();
-    let filtered_result = apply_filters_with_progress(&sender, result, filters).await;
 
-    let _ = sender.send(FiboTaskResult::Result(filtered_result));
+    let mut numbers = apply_transforms_with_progress(progress, numbers, &before);
+    if !filters.is_empty() {
+        numbers = apply_filters_with_progress(progress, numbers, filters);
+    }
+    apply_transforms_with_progress(progress, numbers, &after)
+}
+
+/// The aggregate in place of the numbers, or before them when it keeps them.
+fn send_final_result(
+    builder: &FiboBuilder, sender: &task::FiboTaskSender, numbers: Vec<BigInt>,
+) {
+    let Some(mode) = builder.get_aggregate() else {
+        let _ = sender.send(FiboTaskResult::Result(numbers));
+        return;
+    };
+
+    let aggregate = FiboAggregate::from_values(&numbers, mode.product_modulus);
+    let _ = sender.send(FiboTaskResult::Aggregate(aggregate));
+    if mode.keep_values {
+        let _ = sender.send(FiboTaskResult::Result(numbers));
+    }
+}
+
+fn aggregate_with_progress<I: ImplementationFibo>(
+    sender: &task::FiboTaskSender, start_nums: (BigInt, BigInt), range: Range<usize>,
+    filters: &[FilterFn], mode: &AggregateMode, verify: &mut Option<VerifyStream>,
+) -> FiboAggregate {
+    let total_items = range.len();
+
+    // Verification needs the terms themselves
+    if filters.is_empty() && verify.is_none() {
+        let result = aggregate::closed_form(&start_nums, range, mode);
+        send_progress(sender, total_items, total_items);
+        return result;
+    }
+
+    let mut result = FiboAggregate::new(mode.product_modulus.clone());
+    let numbers = terms_from::<I>(start_nums, range.start).take(total_items);
+
+    for (processed, num) in numbers.enumerate().map(|(i, num)| (i + 1, num)) {
+        if let Some(verify) = verify.as_mut() {
+            verify.push(range.start + processed - 1, &num);
+        }
+        if filters.iter().all(|f| f(&num)) {
+            result.push(&num);
+        }
+
+        if processed % CHUNK_SIZE == 0 || processed == total_items {
+            send_progress(sender, processed, total_items);
+        }
+    }
+
+    result
 }
 
-async fn apply_filters_with_progress(
-    sender: &task::FiboTaskSender, numbers: Vec<BigInt>, filters: &[FilterFn],
+/// Each transform is a stage of its own, reported once it is done.
+fn apply_transforms_with_progress(
+    progress: &mut Progress, numbers: Vec<BigInt>, transforms: &[Transform],
+) -> Vec<BigInt> {
+    let mut numbers = numbers;
+    for transform in transforms {
+        numbers = transform.apply(numbers);
+        progress.report(1, 1);
+        progress.finish_stage();
+    }
+    numbers
+}
+
+fn apply_filters_with_progress(
+    progress: &mut Progress, numbers: Vec<BigInt>, filters: &[FilterFn],
 ) -> Vec<BigInt> {
     let total_items = numbers.len();
     if total_items == 0 {
+        progress.finish_stage();
         return numbers;
     }
 
     if filters.is_empty() {
-        send_progress(sender, total_items, total_items).await;
+        progress.report(total_items, total_items);
+        progress.finish_stage();
         return numbers;
     }
 
@@ -91,26 +531,81 @@ async fn apply_filters_with_progress(
         filtered.extend(filtered_chunk);
         processed += chunk.len();
 
-        send_progress(sender, processed, total_items).await;
+        progress.report(processed, total_items);
     }
 
+    progress.finish_stage();
     filtered
 }
 
-async fn send_progress(sender: &task::FiboTaskSender, processed: usize, total_items: usize) {
+/// G(start), G(start + 1), … with the generator jumped past the skipped terms.
+fn terms_from<I: ImplementationFibo>(
+    start_nums: (BigInt, BigInt), start: usize,
+) -> impl Iterator<Item = BigInt> {
+    let mut rest = I::new(Some(start_nums.clone()));
+    rest.jump_to(start.max(2));
+    [start_nums.0, start_nums.1]
+        .into_iter()
+        .skip(start)
+        .chain(rest)
+}
+
+/// Spreads 0–100 % evenly over the stages of a run (generating the terms,
+/// each transform, filtering), so the bar never goes back when one stage
+/// ends and the next begins.
+struct Progress<'a> {
+    sender: &'a task::FiboTaskSender,
+    stage: usize,
+    stages: usize,
+}
+
+impl<'a> Progress<'a> {
+    fn new(sender: &'a task::FiboTaskSender, stages: usize) -> Self {
+        Self {
+            sender,
+            stage: 0,
+            stages: stages.max(1),
+        }
+    }
+
+    /// Generating, then every stage of `apply_pipeline` that has work to do.
+    fn for_pipeline(sender: &'a task::FiboTaskSender, builder: &FiboBuilder) -> Self {
+        let filtering = usize::from(!builder.get_filters().is_empty());
+        Self::new(sender, 1 + builder.get_transforms().len() + filtering)
+    }
+
+    /// `processed` of `total_items` done in the current stage.
+    fn report(&self, processed: usize, total_items: usize) {
+        let stage = self.stage.min(self.stages - 1);
+        let overall = stage * total_items + processed;
+        send_progress(self.sender, overall, self.stages * total_items);
+    }
+
+    fn finish_stage(&mut self) {
+        self.stage += 1;
+    }
+}
+
+fn send_checkpoint_failure(sender: &task::FiboTaskSender, error: String) {
+    let _ = sender.send(FiboTaskResult::CheckpointFailed(error));
+}
+
+fn send_progress(sender: &task::FiboTaskSender, processed: usize, total_items: usize) {
     let progress = ((processed as f32 / total_items as f32) * 100.0).clamp(0.0, 100.0) as u8;
     let _ = sender.send(FiboTaskResult::Calculation(progress));
 }
 
 #[cfg(test)]
 mod tests {
-    #![warn(clippy::reversed_empty_ranges)]
+    #![allow(clippy::reversed_empty_ranges)]
 
     use super::*;
     use crate::FiboBuilder;
+    use crate::checkpoint::CheckpointConfig;
     use crate::implementation::lineal::LinealFibo;
-    use crate::implementation::matmul::MatmulFibo;
+    use crate::implementation::matmul::{MatmulFibo, nth_term};
     use crate::task::{FiboTaskReceiver, FiboTaskResult};
+    use crate::value_range::ValueRange;
     use num_bigint::BigInt;
     use std::ops::Range;
     use test_case::test_case;
@@ -124,7 +619,7 @@ mod tests {
     async fn test_none_filter() {
         let (tx, mut rx) = make_sender();
         let builder = FiboBuilder::default();
-        calculate_fibo_task::<MatmulFibo>(builder, tx).await;
+        calculate_fibo_task::<MatmulFibo>(builder, tx);
         let msg = rx.recv().await.unwrap();
         assert!(matches!(msg, FiboTaskResult::Result(ref v) if v.is_empty()));
     }
@@ -135,7 +630,7 @@ mod tests {
         let mut builder = FiboBuilder::default();
         builder.set_range_by_id(Some(5..3)); // start > end
         builder.set_start_nums(Some((0.into(), 1.into())));
-        calculate_fibo_task::<LinealFibo>(builder, tx).await;
+        calculate_fibo_task::<LinealFibo>(builder, tx);
         let msg = rx.recv().await.unwrap();
         assert!(matches!(msg, FiboTaskResult::Result(ref v) if v.is_empty()));
     }
@@ -146,7 +641,7 @@ mod tests {
         let mut builder = FiboBuilder::default();
         builder.set_range_by_id(Some(2..2)); // empty range
         builder.set_start_nums(Some((0.into(), 1.into())));
-        calculate_fibo_task::<LinealFibo>(builder, tx).await;
+        calculate_fibo_task::<LinealFibo>(builder, tx);
         let msg = rx.recv().await.unwrap();
         assert!(matches!(msg, FiboTaskResult::Result(ref v) if v.is_empty()));
     }
@@ -163,7 +658,7 @@ mod tests {
         let mut builder = FiboBuilder::default();
         builder.set_range_by_id(Some(range.clone()));
         builder.set_start_nums(Some((start.0.into(), start.1.into())));
-        calculate_fibo_task::<MatmulFibo>(builder, tx).await;
+        calculate_fibo_task::<MatmulFibo>(builder, tx);
 
         let mut progresses = vec![];
         let mut result = None;
@@ -179,6 +674,12 @@ mod tests {
                     result = Some(res_vec);
                     break;
                 }
+                FiboTaskResult::Aggregate(_)
+                | FiboTaskResult::Search(_)
+                | FiboTaskResult::Verification(_)
+                | FiboTaskResult::Written(_)
+                | FiboTaskResult::Polynomials(_)
+                | FiboTaskResult::CheckpointFailed(_) => unreachable!(),
             }
         }
 
@@ -196,7 +697,7 @@ mod tests {
         builder.set_range_by_id(Some(0..12));
         builder.set_start_nums(Some((0.into(), 1.into())));
         builder.add_filter(|n| n % 2u8 == BigInt::from(0));
-        calculate_fibo_task::<LinealFibo>(builder, tx).await;
+        calculate_fibo_task::<LinealFibo>(builder, tx);
 
         let mut progress = vec![];
         let mut result = None;
@@ -210,6 +711,12 @@ mod tests {
                     result = Some(res);
                     break;
                 }
+                FiboTaskResult::Aggregate(_)
+                | FiboTaskResult::Search(_)
+                | FiboTaskResult::Verification(_)
+                | FiboTaskResult::Written(_)
+                | FiboTaskResult::Polynomials(_)
+                | FiboTaskResult::CheckpointFailed(_) => unreachable!(),
             }
         }
 
@@ -221,23 +728,763 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[tokio::test]
+    async fn test_shifted_range_length() {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(5..10));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                let expected: Vec<BigInt> = vec![5, 8, 13, 21, 34].into_iter().map(Into::into).collect();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[test_case(false, 6, 12; "closed form")]
+    #[test_case(true, 3, 10; "streaming with filter")]
+    #[tokio::test]
+    async fn test_aggregate_mode(with_filter: bool, expected_count: usize, expected_sum: i32) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..6));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_aggregate(Some(AggregateMode::default()));
+        if with_filter {
+            builder.add_filter(|n| n >= &BigInt::from(2));
+        }
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Aggregate(res) = msg {
+                assert_eq!(res.count, expected_count);
+                assert_eq!(res.sum, BigInt::from(expected_sum));
+                return;
+            }
+        }
+        panic!("No aggregate received");
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_keeps_values() {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..6));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_aggregate(Some(AggregateMode {
+            keep_values: true,
+            ..Default::default()
+        }));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut summary = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Aggregate(res) => summary = Some(res),
+                FiboTaskResult::Result(res) => {
+                    assert_eq!(res, [0, 1, 1, 2, 3, 5].map(BigInt::from));
+                    assert_eq!(summary.map(|s| s.sum), Some(BigInt::from(12)));
+                    return;
+                }
+                _ => {}
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[test_case(None; "values")]
+    #[test_case(Some(AggregateMode::default()); "aggregate")]
+    #[tokio::test]
+    async fn test_index_selection(aggregate: Option<AggregateMode>) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..1)); // ignored in favour of the selection
+        builder.set_index_selection(Some("3, 10..30 step 5, 50".parse().unwrap()));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_aggregate(aggregate.clone());
+        builder.add_filter(|n| n > &BigInt::from(2));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let expected: Vec<BigInt> = vec![55u64, 610, 6765, 75025, 12586269025]
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Calculation(_)
+                | FiboTaskResult::Search(_)
+                | FiboTaskResult::Verification(_)
+                | FiboTaskResult::Written(_)
+                | FiboTaskResult::Polynomials(_)
+                | FiboTaskResult::CheckpointFailed(_) => {}
+                FiboTaskResult::Result(res) => {
+                    assert!(aggregate.is_none());
+                    assert_eq!(res, expected);
+                    return;
+                }
+                FiboTaskResult::Aggregate(res) => {
+                    assert!(aggregate.is_some());
+                    assert_eq!(res.count, expected.len());
+                    assert_eq!(res.sum, expected.iter().sum::<BigInt>());
+                    return;
+                }
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[test_case(None; "list")]
+    #[test_case(Some(AggregateMode::default()); "aggregate")]
+    #[tokio::test]
+    async fn test_transform_stages(aggregate: Option<AggregateMode>) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..10));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_aggregate(aggregate.clone());
+        builder.add_filter(|n| n % 2u8 == BigInt::from(0));
+        builder.add_transform(TransformStage::AfterFilters, Transform::PartialSums);
+        builder.add_transform(TransformStage::BeforeFilters, Transform::Squares);
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        // squares 0 1 1 4 9 25 64 169 441 1156, even ones, then partial sums
+        let expected: Vec<BigInt> = vec![0, 4, 68, 1224].into_iter().map(Into::into).collect();
+        let mut last_progress = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Calculation(p) => last_progress = Some(p),
+                FiboTaskResult::Result(res) => {
+                    assert!(aggregate.is_none());
+                    assert_eq!(res, expected);
+                    break;
+                }
+                FiboTaskResult::Aggregate(res) => {
+                    assert!(aggregate.is_some());
+                    assert_eq!(res.count, expected.len());
+                    assert_eq!(res.sum, expected.iter().sum::<BigInt>());
+                    break;
+                }
+                FiboTaskResult::Search(_)
+                | FiboTaskResult::Verification(_)
+                | FiboTaskResult::Written(_)
+                | FiboTaskResult::Polynomials(_)
+                | FiboTaskResult::CheckpointFailed(_) => unreachable!(),
+            }
+        }
+        assert_eq!(last_progress, Some(100));
+    }
+
+    #[tokio::test]
+    async fn test_transform_on_selection() {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_index_selection(Some("10..14, 20".parse().unwrap()));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        let modulo = Transform::modulo(100.into()).unwrap();
+        builder.add_transform(TransformStage::AfterFilters, modulo);
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                // 55 89 144 233 6765
+                let expected: Vec<BigInt> = vec![55, 89, 44, 33, 65].into_iter().map(Into::into).collect();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[tokio::test]
+    async fn test_value_range_overrides_index_range() {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..5));
+        builder.set_value_range(Some(ValueRange::new((-1).into(), 2.into()).unwrap()));
+        builder.set_start_nums(Some((5.into(), (-3).into())));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                // 5 -3 [2 -1 1 0 1 1 2] 3 5
+                let expected: Vec<BigInt> = vec![2, -1, 1, 0, 1, 1, 2].into_iter().map(Into::into).collect();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    /// Relies on the default `jump_to` and `term` hooks.
+    struct PlainFibo {
+        pair: (BigInt, BigInt),
+    }
+
+    impl Iterator for PlainFibo {
+        type Item = BigInt;
+
+        fn next(&mut self) -> Option<BigInt> {
+            let next = &self.pair.0 + &self.pair.1;
+            self.pair = (std::mem::take(&mut self.pair.1), next.clone());
+            Some(next)
+        }
+    }
+
+    impl ImplementationFibo for PlainFibo {
+        fn new(start_nums: Option<(BigInt, BigInt)>) -> Self {
+            Self {
+                pair: start_nums.unwrap_or((BigInt::from(-1), BigInt::from(1))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_hooks() {
+        let start_nums: (BigInt, BigInt) = ((-4).into(), 7.into());
+        for n in [0, 1, 2, 3, 77] {
+            let expected = nth_term(&start_nums, n);
+            assert_eq!(PlainFibo::term(start_nums.clone(), n), expected);
+            assert_eq!(LinealFibo::term(start_nums.clone(), n), expected);
+            assert_eq!(MatmulFibo::term(start_nums.clone(), n), expected);
+
+            let mut builder = FiboBuilder::default();
+            builder.set_start_nums(Some(start_nums.clone()));
+            let term = crate::FiboCalc::new(builder).calc_term_with::<PlainFibo>(n);
+            assert_eq!(term, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_calc_background_with_custom_implementation() {
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(30..45));
+        builder.set_start_nums(Some((2.into(), 1.into())));
+        builder.add_filter(|n| n % 3u8 == BigInt::from(0));
+        let mut rx = crate::FiboCalc::new(builder).calc_background_with::<PlainFibo>();
+
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                let expected: Vec<BigInt> = (30..45)
+                    .map(|n| nth_term(&(2.into(), 1.into()), n))
+                    .filter(|n| n % 3u8 == BigInt::from(0))
+                    .collect();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_runtime_stays_responsive() {
+        // The first filter call parks the calculation until `release` is dropped;
+        // the timeout only keeps a broken build from hanging
+        let (release, gate) = std::sync::mpsc::channel::<()>();
+        let gate = std::sync::Mutex::new(gate);
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..6_000));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_aggregate(Some(AggregateMode::default()));
+        builder.add_filter(move |n| {
+            let _ = gate
+                .lock()
+                .unwrap()
+                .recv_timeout(std::time::Duration::from_secs(10));
+            n.bit(0)
+        });
+        let mut rx = crate::FiboCalc::new(builder).calc_background();
+
+        // The only runtime thread still runs other tasks and timers meanwhile
+        let other = tokio::spawn(async {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            42
+        });
+        assert_eq!(other.await.unwrap(), 42);
+        assert!(rx.try_recv().is_err(), "calculation ran on the runtime thread");
+
+        drop(release);
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Aggregate(res) = msg {
+                assert_eq!(res.count, 4_000);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    async fn collect_search(builder: FiboBuilder) -> (Vec<BigInt>, Vec<SearchProgress>) {
+        let (tx, mut rx) = make_sender();
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut progress = vec![];
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Search(p) => progress.push(p),
+                FiboTaskResult::Result(res) => return (res, progress),
+                _ => {}
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[tokio::test]
+    async fn test_search_first_matches() {
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.add_filter(|n| n % 1000 == BigInt::from(0));
+        builder.set_search(Some(FirstMatches::new(3)));
+
+        let (res, progress) = collect_search(builder).await;
+        let expected: Vec<BigInt> = [0, 750, 1500]
+            .into_iter()
+            .map(|n| nth_term(&(0.into(), 1.into()), n))
+            .collect();
+        assert_eq!(res, expected);
+        assert_eq!(
+            progress.last(),
+            Some(&SearchProgress { matches: 3, scanned: 1501 })
+        );
+        assert!(progress.windows(2).all(|w| w[0].scanned <= w[1].scanned));
+    }
+
+    #[tokio::test]
+    async fn test_search_first_thousand_digit_term() {
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.add_filter(|n| n.to_string().len() >= 1000);
+        builder.set_search(Some(FirstMatches::new(1)));
+
+        let (res, progress) = collect_search(builder).await;
+        assert_eq!(res, vec![nth_term(&(0.into(), 1.into()), 4782)]);
+        assert_eq!(progress.last().map(|p| p.scanned), Some(4783));
+    }
+
+    #[tokio::test]
+    async fn test_search_applies_before_filter_transforms() {
+        let make_builder = || {
+            let mut builder = FiboBuilder::default();
+            builder.set_start_nums(Some((0.into(), 1.into())));
+            builder.add_transform(TransformStage::BeforeFilters, Transform::Differences);
+            builder.add_transform(TransformStage::BeforeFilters, Transform::PartialSums);
+            builder.add_filter(|n| n.bit(0));
+            builder
+        };
+
+        let (tx, rx) = make_sender();
+        let mut builder = make_builder();
+        builder.set_range_by_id(Some(0..40));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+        let expected = final_values(rx).await;
+
+        let mut builder = make_builder();
+        builder.set_search(Some(FirstMatches::new(expected.len())));
+        let (res, _) = collect_search(builder).await;
+        assert!(expected.len() > 10);
+        assert_eq!(res, expected);
+    }
+
+    #[tokio::test]
+    async fn test_search_stops_at_index_limit() {
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some((2.into(), 1.into())));
+        builder.add_filter(|n| n < &BigInt::from(0));
+        builder.set_search(Some(FirstMatches {
+            count: 5,
+            start: 10,
+            index_limit: 2500,
+        }));
+
+        let (res, progress) = collect_search(builder).await;
+        assert!(res.is_empty());
+        assert_eq!(
+            progress.last(),
+            Some(&SearchProgress { matches: 0, scanned: 2490 })
+        );
+    }
+
+    #[test_case(None; "range")]
+    #[test_case(Some("0..1500, 2000..3000"); "selection")]
+    #[tokio::test]
+    async fn test_progress_never_goes_back(selection: Option<&str>) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..3_000));
+        builder.set_index_selection(selection.map(|s| s.parse().unwrap()));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.add_transform(TransformStage::BeforeFilters, Transform::Squares);
+        builder.add_filter(|n| n.bit(0));
+        builder.add_transform(TransformStage::AfterFilters, Transform::PartialSums);
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut reported = Vec::new();
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Calculation(progress) = msg {
+                reported.push(progress);
+            }
+        }
+        assert!(reported.is_sorted(), "progress went back: {:?}", reported);
+        assert_eq!(reported.last(), Some(&100));
+        // Generating is one of four stages
+        assert!(reported.contains(&25));
+    }
+
     #[tokio::test]
     async fn test_apply_filters_with_progress_empty() {
         let (tx, mut rx) = make_sender();
         let numbers: Vec<BigInt> = vec![];
         let filters: Vec<FilterFn> = vec![];
-        let res = apply_filters_with_progress(&tx, numbers.clone(), &filters).await;
+        let mut progress = Progress::new(&tx, 1);
+        let res = apply_filters_with_progress(&mut progress, numbers.clone(), &filters);
         assert_eq!(res, numbers);
         // No progress message expected
         assert!(rx.try_recv().is_err());
     }
 
+    #[test_case(None; "range")]
+    #[test_case(Some("0..20, 40, 41, 42"); "selection")]
+    #[tokio::test]
+    async fn test_verify(selection: Option<&str>) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..60));
+        builder.set_index_selection(selection.map(|s| s.parse().unwrap()));
+        builder.set_start_nums(Some((3.into(), (-7).into())));
+        builder.add_filter(|n| n.bit(0));
+        builder.set_verify(true);
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut verifications = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Verification(res) => verifications = Some(res),
+                FiboTaskResult::Result(_) => break,
+                _ => {}
+            }
+        }
+
+        let verifications = verifications.expect("No verification received");
+        let expected = match selection {
+            Some(_) => 23,
+            None => 60,
+        };
+        assert_eq!(verifications.len(), expected);
+        assert!(verifications.iter().all(|v| v.is_verified()));
+    }
+
+    #[test_case("aggregate", 0, 60; "aggregate")]
+    #[test_case("closed form", 0, 60; "closed form aggregate")]
+    #[test_case("search", 10, 7; "search")]
+    #[test_case("sink", 0, 60; "sink")]
+    #[tokio::test]
+    async fn test_verify_streamed(path: &str, first: usize, expected: usize) {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..60));
+        builder.set_start_nums(Some((3.into(), (-7).into())));
+        builder.set_verify(true);
+        if path != "closed form" {
+            builder.add_filter(|n| n.bit(0));
+        }
+        match path {
+            "search" => builder.set_search(Some(FirstMatches {
+                count: 5,
+                start: 10,
+                index_limit: 1_000,
+            })),
+            "sink" => builder.set_sink(Some(CollectSink {
+                terms: std::sync::Arc::default(),
+                limit: usize::MAX,
+            })),
+            _ => builder.set_aggregate(Some(AggregateMode::default())),
+        };
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut verifications = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Verification(res) => verifications = Some(res),
+                FiboTaskResult::Result(_)
+                | FiboTaskResult::Aggregate(_)
+                | FiboTaskResult::Written(_) => break,
+                _ => {}
+            }
+        }
+
+        let verifications = verifications.expect("No verification received");
+        assert_eq!(verifications.len(), expected);
+        for (index, verification) in (first..).zip(&verifications) {
+            assert_eq!(verification.index, BigInt::from(index));
+            assert!(verification.is_verified());
+        }
+    }
+
+    #[test]
+    fn test_calc_term_verified() {
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some((2.into(), 1.into())));
+        let (value, verification) = crate::FiboCalc::new(builder).calc_term_verified(1_000.into());
+
+        assert_eq!(value, nth_term(&(2.into(), 1.into()), 1_000));
+        assert_eq!(verification.index, BigInt::from(1_000));
+        assert!(verification.is_verified());
+    }
+
+    #[test_case(0; "first")]
+    #[test_case(1; "second")]
+    #[test_case(250; "later")]
+    #[test_case(-1; "minus one")]
+    #[test_case(-2; "minus two")]
+    #[test_case(-3; "minus three")]
+    #[test_case(-8; "minus eight")]
+    #[test_case(-250; "far back")]
+    fn test_calc_term_agrees_with_calc_many(n: i64) {
+        let start_nums: (BigInt, BigInt) = (5.into(), (-3).into());
+        let make_calc = || {
+            let mut builder = FiboBuilder::default();
+            builder.set_start_nums(Some(start_nums.clone()));
+            crate::FiboCalc::new(builder)
+        };
+
+        let term = make_calc().calc_term(n.into());
+        if let Ok(n) = usize::try_from(n) {
+            assert_eq!(term, nth_term(&start_nums, n));
+        }
+        assert_eq!(make_calc().calc_many([n]), [term]);
+    }
+
+    async fn final_values(mut rx: FiboTaskReceiver) -> Vec<BigInt> {
+        while let Some(msg) = rx.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                return res;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[tokio::test]
+    async fn test_resume_range_matches_uninterrupted_run() {
+        let path = std::env::temp_dir().join(format!("fibo_resume_{}", std::process::id()));
+        let start_nums: (BigInt, BigInt) = (5.into(), (-2).into());
+        let range = 3..250;
+        let make_builder = || {
+            let mut builder = FiboBuilder::default();
+            builder.set_range_by_id(Some(range.clone()));
+            builder.set_start_nums(Some(start_nums.clone()));
+            builder.add_filter(|n| n.bit(0));
+            builder
+        };
+
+        let (tx, rx) = make_sender();
+        calculate_fibo_task::<LinealFibo>(make_builder(), tx);
+        let expected = final_values(rx).await;
+
+        // Interrupted after the first 120 terms
+        let config = CheckpointConfig {
+            path: path.clone(),
+            interval: std::time::Duration::ZERO,
+        };
+        let partial: Vec<BigInt> =
+            range.clone().take(120).map(|n| nth_term(&start_nums, n)).collect();
+        let mut checkpointer =
+            RangeCheckpointer::new(config, start_nums.clone(), range.clone(), 0, 0);
+        checkpointer.maybe_save(&partial[..80]).unwrap();
+        checkpointer.maybe_save(&partial).unwrap();
+
+        let rx = crate::FiboCalc::new(make_builder()).resume(&path).unwrap();
+        assert_eq!(final_values(rx).await, expected);
+        assert!(Checkpoint::load(&path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_checkpointed_calc_one_background() {
+        let path = std::env::temp_dir().join(format!("fibo_calc_one_{}", std::process::id()));
+        let start_nums: (BigInt, BigInt) = ((-4).into(), 9.into());
+        let n = BigInt::from(12_345);
+
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some(start_nums.clone()));
+        let expected = crate::FiboCalc::new(builder).calc_one_number(n.clone());
+
+        let mut builder = FiboBuilder::default();
+        builder.set_start_nums(Some(start_nums));
+        builder.set_checkpoint(Some(CheckpointConfig::new(&path)));
+        let rx = crate::FiboCalc::new(builder).calc_one_background(n);
+        assert_eq!(final_values(rx).await, [expected]);
+        assert!(Checkpoint::load(&path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_checkpoint_saves_are_reported() {
+        let dir = std::env::temp_dir().join(format!("fibo_missing_{}", std::process::id()));
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..100));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_checkpoint(Some(CheckpointConfig {
+            path: dir.join("range"),
+            interval: std::time::Duration::ZERO,
+        }));
+
+        let (tx, mut rx) = make_sender();
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+        let (mut failures, mut result) = (0, None);
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::CheckpointFailed(_) => failures += 1,
+                FiboTaskResult::Result(res) => result = Some(res),
+                _ => {}
+            }
+        }
+        assert!(failures > 0);
+        assert_eq!(result.map(|res| res.len()), Some(100));
+    }
+
+    #[test]
+    fn test_resume_rejects_mismatched_next() {
+        let path = std::env::temp_dir().join(format!("fibo_mismatch_{}", std::process::id()));
+        let start_nums: (BigInt, BigInt) = (0.into(), 1.into());
+        let terms: Vec<BigInt> = (0..30).map(|n| nth_term(&start_nums, n)).collect();
+        let config = CheckpointConfig {
+            path: path.clone(),
+            interval: std::time::Duration::ZERO,
+        };
+        let mut checkpointer = RangeCheckpointer::new(config, start_nums, 0..50, 0, 0);
+        checkpointer.maybe_save(&terms).unwrap();
+
+        let mut checkpoint = Checkpoint::load(&path).unwrap();
+        if let CheckpointJob::Range { next, .. } = &mut checkpoint.job {
+            *next += 5;
+        }
+        checkpoint.save(&path).unwrap();
+        let resumed = crate::FiboCalc::new(FiboBuilder::default()).resume(&path);
+        assert_eq!(
+            resumed.err(),
+            Some(String::from("Checkpoint expects terms up to 35 but its results end at 30"))
+        );
+        Checkpoint::remove(&path);
+    }
+
+    /// Shares what it receives with the test, or fails after `limit` terms.
+    struct CollectSink {
+        terms: std::sync::Arc<std::sync::Mutex<Vec<(usize, BigInt)>>>,
+        limit: usize,
+    }
+
+    impl ResultSink for CollectSink {
+        fn write(&mut self, index: usize, value: &BigInt) -> Result<(), String> {
+            let mut terms = self.terms.lock().unwrap();
+            if terms.len() == self.limit {
+                return Err(String::from("Disk full"));
+            }
+            terms.push((index, value.clone()));
+            Ok(())
+        }
+    }
+
+    #[test_case(None, usize::MAX => Ok(33); "range")]
+    #[test_case(Some("0..20, 40, 41, 42"), usize::MAX => Ok(16); "selection")]
+    #[test_case(None, 5 => Err(String::from("Disk full")); "failing sink")]
+    #[tokio::test]
+    async fn test_sink(selection: Option<&str>, limit: usize) -> Result<usize, String> {
+        let (tx, mut rx) = make_sender();
+        let start_nums: (BigInt, BigInt) = (3.into(), (-7).into());
+        let terms = std::sync::Arc::default();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(10..60));
+        builder.set_index_selection(selection.map(|s| s.parse().unwrap()));
+        builder.set_start_nums(Some(start_nums.clone()));
+        builder.add_filter(|n| n.bit(0));
+        builder.set_sink(Some(CollectSink {
+            terms: std::sync::Arc::clone(&terms),
+            limit,
+        }));
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut written = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Written(res) => written = Some(res),
+                FiboTaskResult::Calculation(_) => {}
+                _ => panic!("Only progress and the written count are expected"),
+            }
+        }
+
+        for (index, value) in terms.lock().unwrap().iter() {
+            assert_eq!(value, &nth_term(&start_nums, *index));
+            assert!(value.bit(0));
+        }
+        written.expect("No written count received")
+    }
+
+    #[test_case("transform" => Err(String::from("A sink cannot be combined with transforms")))]
+    #[test_case("aggregate" => Err(String::from("A sink cannot be combined with an aggregate")))]
+    #[test_case("search" => Err(String::from("A sink cannot be combined with a search")))]
+    #[test_case("checkpoint" => Err(String::from("A sink cannot be combined with a checkpoint")))]
+    #[tokio::test]
+    async fn test_sink_rejects_combinations(setting: &str) -> Result<usize, String> {
+        let (tx, mut rx) = make_sender();
+        let terms = std::sync::Arc::default();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(0..10));
+        builder.set_start_nums(Some((0.into(), 1.into())));
+        builder.set_sink(Some(CollectSink {
+            terms: std::sync::Arc::clone(&terms),
+            limit: usize::MAX,
+        }));
+        match setting {
+            "transform" => builder.add_transform(TransformStage::AfterFilters, Transform::Squares),
+            "aggregate" => builder.set_aggregate(Some(AggregateMode::default())),
+            "search" => builder.set_search(Some(FirstMatches::new(3))),
+            _ => builder.set_checkpoint(Some(CheckpointConfig::new("unused.checkpoint"))),
+        };
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        assert!(terms.lock().unwrap().is_empty());
+        match rx.recv().await {
+            Some(FiboTaskResult::Written(written)) => written,
+            _ => panic!("Expected the written count first"),
+        }
+    }
+
+    #[test_case(false => Ok(vec![String::from("x^2 + 1"), String::from("x^5 + 4x^3 + 3x")]))]
+    #[test_case(true => Err(String::from("Polynomials cannot be combined with an aggregate")))]
+    #[tokio::test]
+    async fn test_polynomials(aggregate: bool) -> Result<Vec<String>, String> {
+        let (tx, mut rx) = make_sender();
+        let mut builder = FiboBuilder::default();
+        builder.set_range_by_id(Some(3..7));
+        builder.set_polynomials(Some(PolynomialKind::Fibonacci));
+        builder.add_filter(|n| !n.bit(0));
+        if aggregate {
+            builder.set_aggregate(Some(AggregateMode::default()));
+        }
+        calculate_fibo_task::<LinealFibo>(builder, tx);
+
+        let mut last_progress = None;
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                FiboTaskResult::Calculation(p) => last_progress = Some(p),
+                FiboTaskResult::Polynomials(res) => {
+                    assert_eq!(last_progress, res.is_ok().then_some(100));
+                    return res.map(|res| res.iter().map(ToString::to_string).collect());
+                }
+                _ => panic!("Only progress and polynomials are expected"),
+            }
+        }
+        panic!("No polynomials received")
+    }
+
     #[tokio::test]
     async fn test_apply_filters_with_progress_no_filters() {
         let (tx, mut rx) = make_sender();
         let numbers: Vec<BigInt> = vec![1.into(), 2.into(), 3.into()];
         let filters: Vec<FilterFn> = vec![];
-        let res = apply_filters_with_progress(&tx, numbers.clone(), &filters).await;
+        let mut progress = Progress::new(&tx, 1);
+        let res = apply_filters_with_progress(&mut progress, numbers.clone(), &filters);
         assert_eq!(res, numbers);
         // Should send 100% progress
         let msg = rx.recv().await.unwrap();
@@ -249,7 +1496,8 @@ mod tests {
         let (tx, mut rx) = make_sender();
         let numbers: Vec<BigInt> = (0..10).map(Into::into).collect();
         let filters: Vec<FilterFn> = vec![Box::new(|n| n % 2u8 == BigInt::from(0))];
-        let res = apply_filters_with_progress(&tx, numbers.clone(), &filters).await;
+        let mut progress = Progress::new(&tx, 1);
+        let res = apply_filters_with_progress(&mut progress, numbers.clone(), &filters);
         let expected: Vec<BigInt> = (0..10).filter(|n| n % 2 == 0).map(Into::into).collect();
         assert_eq!(res, expected);
         // Should send progress at least once
@@ -261,11 +1509,11 @@ mod tests {
     async fn test_send_progress_clamps() {
         let (tx, mut rx) = make_sender();
         // processed > total_items
-        send_progress(&tx, 15, 10).await;
+        send_progress(&tx, 15, 10);
         let msg = rx.recv().await.unwrap();
         assert!(matches!(msg, FiboTaskResult::Calculation(100)));
         // processed < 0 (should clamp to 0)
-        send_progress(&tx, 0, 10).await;
+        send_progress(&tx, 0, 10);
         let msg = rx.recv().await.unwrap();
         assert!(matches!(msg, FiboTaskResult::Calculation(0)));
     }
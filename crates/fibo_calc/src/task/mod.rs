@@ -1,7 +1,11 @@
 mod calculate_fibo_task;
 
-pub use calculate_fibo_task::calculate_fibo_task;
+pub use calculate_fibo_task::{calculate_fibo_task, calculate_one_task, resume_fibo_task};
 
+use crate::aggregate::FiboAggregate;
+use crate::polynomial::FiboPolynomial;
+use crate::search::SearchProgress;
+use crate::verify::Verification;
 use num_bigint::BigInt;
 use tokio::sync::mpsc;
 
@@ -9,6 +13,17 @@ pub enum FiboTaskResult {
     /// Return % progress
     Calculation(u8),
     Result(Vec<BigInt>),
+    Aggregate(FiboAggregate),
+    /// Progress of an open-ended search instead of a percentage
+    Search(SearchProgress),
+    /// Verdicts for the generated terms, sent before `Result` when verifying
+    Verification(Vec<Verification>),
+    /// Terms written to the builder's sink, or why writing stopped
+    Written(Result<usize, String>),
+    /// Polynomials of the range or selection, or why they cannot be built
+    Polynomials(Result<Vec<FiboPolynomial>, String>),
+    /// A checkpoint could not be saved; the calculation carries on without it
+    CheckpointFailed(String),
 }
 
 pub type FiboTaskSender = mpsc::UnboundedSender<FiboTaskResult>;
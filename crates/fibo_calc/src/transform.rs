@@ -0,0 +1,268 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Maps the calculated terms to a derived sequence. Stages that look at
+/// neighbours use the terms in output order, so the result is one or two
+/// shorter than the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transform {
+    /// x₀, x₀ + x₁, x₀ + x₁ + x₂, …
+    PartialSums,
+    /// xᵢ₊₁ − xᵢ
+    Differences,
+    /// xᵢ²
+    Squares,
+    /// xᵢ·xᵢ₊₁
+    ConsecutiveProducts,
+    /// xᵢ² − xᵢ₋₁·xᵢ₊₁, which is ±1 for the classic sequence
+    Cassini,
+    /// xᵢ mod m in [0, m), built with `Transform::modulo`
+    Modulo(Modulus),
+    /// Sum of the decimal digits of |xᵢ|
+    DigitSum,
+}
+
+/// A modulus known to be > 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Modulus(BigInt);
+
+impl Modulus {
+    pub fn value(&self) -> &BigInt {
+        &self.0
+    }
+}
+
+/// Where a transform runs relative to the filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformStage {
+    BeforeFilters,
+    AfterFilters,
+}
+
+impl Transform {
+    pub fn modulo(modulus: BigInt) -> Result<Self, String> {
+        if !modulus.is_positive() {
+            return Err(String::from("Modulus must be > 0"));
+        }
+        Ok(Transform::Modulo(Modulus(modulus)))
+    }
+
+    pub fn apply(&self, values: Vec<BigInt>) -> Vec<BigInt> {
+        match self {
+            Transform::PartialSums => {
+                let mut sum = BigInt::zero();
+                values
+                    .into_iter()
+                    .map(|value| {
+                        sum += value;
+                        sum.clone()
+                    })
+                    .collect()
+            }
+            Transform::Differences => values.windows(2).map(|w| &w[1] - &w[0]).collect(),
+            Transform::Squares => values.iter().map(|value| value * value).collect(),
+            Transform::ConsecutiveProducts => values.windows(2).map(|w| &w[0] * &w[1]).collect(),
+            Transform::Cassini => values
+                .windows(3)
+                .map(|w| &w[1] * &w[1] - &w[0] * &w[2])
+                .collect(),
+            Transform::Modulo(modulus) => values
+                .iter()
+                .map(|value| value.mod_floor(modulus.value()))
+                .collect(),
+            Transform::DigitSum => values.iter().map(digit_sum).collect(),
+        }
+    }
+}
+
+/// `Transform::apply` one term at a time, for scans with no fixed end.
+pub(crate) struct TransformStream {
+    transform: Transform,
+    /// The last inputs, enough for one more window of neighbours
+    recent: Vec<BigInt>,
+    sum: BigInt,
+}
+
+impl TransformStream {
+    pub(crate) fn new(transform: Transform) -> Self {
+        Self {
+            transform,
+            recent: Vec::new(),
+            sum: BigInt::zero(),
+        }
+    }
+
+    /// The next output, or None while the first window is still filling.
+    pub(crate) fn push(&mut self, value: BigInt) -> Option<BigInt> {
+        let window = match self.transform {
+            Transform::PartialSums => {
+                self.sum += value;
+                return Some(self.sum.clone());
+            }
+            Transform::Differences | Transform::ConsecutiveProducts => 2,
+            Transform::Cassini => 3,
+            _ => 1,
+        };
+
+        self.recent.push(value);
+        if self.recent.len() < window {
+            return None;
+        }
+        let output = self.transform.apply(self.recent.clone()).pop();
+        self.recent.remove(0);
+        output
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transform::PartialSums => write!(f, "partial sums"),
+            Transform::Differences => write!(f, "differences"),
+            Transform::Squares => write!(f, "squares"),
+            Transform::ConsecutiveProducts => write!(f, "consecutive products"),
+            Transform::Cassini => write!(f, "cassini"),
+            Transform::Modulo(modulus) => write!(f, "mod {}", modulus.value()),
+            Transform::DigitSum => write!(f, "digit sum"),
+        }
+    }
+}
+
+/// Parses the names printed by `Display`, e.g. `squares` or `mod 1000`.
+impl FromStr for Transform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(modulus) = s.strip_prefix("mod") {
+            let modulus = modulus
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid modulus '{}'", modulus.trim()))?;
+            return Transform::modulo(modulus);
+        }
+
+        match s {
+            "partial sums" => Ok(Transform::PartialSums),
+            "differences" => Ok(Transform::Differences),
+            "squares" => Ok(Transform::Squares),
+            "consecutive products" => Ok(Transform::ConsecutiveProducts),
+            "cassini" => Ok(Transform::Cassini),
+            "digit sum" => Ok(Transform::DigitSum),
+            _ => Err(format!("Unknown transform '{}'", s)),
+        }
+    }
+}
+
+fn digit_sum(value: &BigInt) -> BigInt {
+    let sum: u64 = value
+        .magnitude()
+        .to_radix_le(10)
+        .into_iter()
+        .map(u64::from)
+        .sum();
+    BigInt::from(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn ints(values: &[i64]) -> Vec<BigInt> {
+        values.iter().copied().map(BigInt::from).collect()
+    }
+
+    fn classic(range: std::ops::Range<usize>) -> Vec<BigInt> {
+        range
+            .map(|n| nth_term(&(BigInt::zero(), BigInt::from(1)), n))
+            .collect()
+    }
+
+    #[test_case(Transform::PartialSums, &[0, 1, 2, 4, 7, 12, 20]; "partial sums")]
+    #[test_case(Transform::Differences, &[1, 0, 1, 1, 2, 3]; "differences")]
+    #[test_case(Transform::Squares, &[0, 1, 1, 4, 9, 25, 64]; "squares")]
+    #[test_case(Transform::ConsecutiveProducts, &[0, 1, 2, 6, 15, 40]; "consecutive products")]
+    #[test_case(Transform::Cassini, &[1, -1, 1, -1, 1]; "cassini")]
+    #[test_case(Transform::modulo(BigInt::from(3)).unwrap(), &[0, 1, 1, 2, 0, 2, 2]; "modulo")]
+    fn test_apply(transform: Transform, expected: &[i64]) {
+        assert_eq!(transform.apply(classic(0..7)), ints(expected));
+    }
+
+    #[test]
+    fn test_partial_sums_identity() {
+        // F(0) + … + F(n) = F(n+2) − 1
+        let sums = Transform::PartialSums.apply(classic(0..50));
+        let expected: Vec<BigInt> = classic(2..52).into_iter().map(|f| f - 1).collect();
+        assert_eq!(sums, expected);
+    }
+
+    #[test]
+    fn test_digit_sum_and_negative_modulo() {
+        let values = ints(&[-832_040, 0, 99]);
+        assert_eq!(
+            Transform::DigitSum.apply(values.clone()),
+            ints(&[17, 0, 18])
+        );
+        assert_eq!(
+            Transform::modulo(BigInt::from(7)).unwrap().apply(values),
+            ints(&[1, 0, 1])
+        );
+    }
+
+    #[test]
+    fn test_composition() {
+        // Differences of partial sums give back the terms after the first
+        let terms = classic(0..20);
+        let round_trip = Transform::Differences.apply(Transform::PartialSums.apply(terms.clone()));
+        assert_eq!(round_trip, terms[1..]);
+    }
+
+    #[test_case(Transform::PartialSums; "partial sums")]
+    #[test_case(Transform::Differences; "differences")]
+    #[test_case(Transform::ConsecutiveProducts; "consecutive products")]
+    #[test_case(Transform::Cassini; "cassini")]
+    #[test_case(Transform::DigitSum; "digit sum")]
+    fn test_stream_matches_apply(transform: Transform) {
+        let terms = classic(0..30);
+        let mut stream = TransformStream::new(transform.clone());
+        let streamed: Vec<BigInt> = terms.iter().filter_map(|t| stream.push(t.clone())).collect();
+        assert_eq!(streamed, transform.apply(terms));
+    }
+
+    #[test]
+    fn test_short_inputs() {
+        assert!(Transform::Cassini.apply(ints(&[1, 2])).is_empty());
+        assert!(Transform::Differences.apply(ints(&[1])).is_empty());
+        assert!(Transform::PartialSums.apply(vec![]).is_empty());
+    }
+
+    #[test_case("mod 1000", Transform::modulo(BigInt::from(1000)); "modulo")]
+    #[test_case(" partial sums ", Ok(Transform::PartialSums); "trimmed")]
+    #[test_case("mod 0", Err(String::from("Modulus must be > 0")); "zero modulus")]
+    #[test_case("mod x", Err(String::from("Invalid modulus 'x'")); "invalid modulus")]
+    #[test_case("cubes", Err(String::from("Unknown transform 'cubes'")); "unknown")]
+    fn test_parse(input: &str, expected: Result<Transform, String>) {
+        assert_eq!(input.parse::<Transform>(), expected);
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let transforms = [
+            Transform::PartialSums,
+            Transform::Differences,
+            Transform::Squares,
+            Transform::ConsecutiveProducts,
+            Transform::Cassini,
+            Transform::modulo(BigInt::from(97)).unwrap(),
+            Transform::DigitSum,
+        ];
+        for transform in transforms {
+            assert_eq!(transform.to_string().parse(), Ok(transform));
+        }
+    }
+}
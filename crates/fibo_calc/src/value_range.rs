@@ -0,0 +1,207 @@
+use crate::implementation::doubling::{fibo_pair, jump_pair};
+use crate::selection::IndexSelection;
+use num_bigint::BigInt;
+use num_traits::{Signed, ToPrimitive, Zero};
+use std::fmt::Display;
+
+/// log₂(φ), turns a bit-length gap into an index gap.
+const LOG2_PHI: f64 = 0.694_241_913_630_617_3;
+
+/// Closed interval [low, high] of term values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueRange {
+    low: BigInt,
+    high: BigInt,
+}
+
+impl ValueRange {
+    pub fn new(low: BigInt, high: BigInt) -> Result<Self, String> {
+        if high < low {
+            return Err(String::from("Value range high must be >= low"));
+        }
+        Ok(Self { low, high })
+    }
+
+    pub fn low(&self) -> &BigInt {
+        &self.low
+    }
+
+    pub fn high(&self) -> &BigInt {
+        &self.high
+    }
+
+    pub fn contains(&self, value: &BigInt) -> bool {
+        &self.low <= value && value <= &self.high
+    }
+
+    /// Indices n ≥ 0 with G(n) in the range. The non-monotone prefix is
+    /// walked term by term; the monotone tail is bounded by a log_φ estimate
+    /// corrected with exact terms. The all-zero sequence only reports 0.
+    pub fn indices(&self, start_nums: &(BigInt, BigInt)) -> IndexSelection {
+        let (mut pre, mut post) = start_nums.clone();
+        if pre.is_zero() && post.is_zero() {
+            return if self.contains(&pre) {
+                IndexSelection::indices([0])
+            } else {
+                IndexSelection::default()
+            };
+        }
+
+        let mut prefix = Vec::new();
+        let mut n = 0;
+        while pre.sign() != post.sign() || pre.is_zero() {
+            if self.contains(&pre) {
+                prefix.push(n);
+            }
+            let next = &pre + &post;
+            pre = std::mem::replace(&mut post, next);
+            n += 1;
+        }
+
+        // |pre| may still exceed |post|, the tail is strictly monotone from `post` on
+        if self.contains(&pre) {
+            prefix.push(n);
+        }
+        let next = &pre + &post;
+        pre = std::mem::replace(&mut post, next);
+        n += 1;
+
+        // A falling tail is the rising tail of −G in [−high, −low]
+        let (tail, low, high) = if pre.is_negative() {
+            ((-pre, -post), -&self.high, -&self.low)
+        } else {
+            ((pre, post), self.low.clone(), self.high.clone())
+        };
+        let first = n + first_at_least(&tail, &low);
+        let end = n + first_at_least(&tail, &(high + 1));
+
+        let selection = IndexSelection::indices(prefix);
+        if first < end {
+            selection.union(IndexSelection::range(first..end))
+        } else {
+            selection
+        }
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.low, self.high)
+    }
+}
+
+/// Smallest offset m with t(m) ≥ target for a positive, strictly rising
+/// tail t given as (t(0), t(1)).
+fn first_at_least(tail: &(BigInt, BigInt), target: &BigInt) -> usize {
+    let (t0, t1) = tail;
+    if t0 >= target {
+        return 0;
+    }
+    if t1 >= target {
+        return 1;
+    }
+
+    // t(1 + g) ≈ t(1)·φ^g once the ratio settles
+    let estimate = ((log2(target) - log2(t1)) / LOG2_PHI).ceil().max(0.0) as usize;
+    let mut offset = estimate + 1;
+    let (mut current, mut next) = jump_pair(tail, &fibo_pair(&BigInt::from(offset)));
+
+    while &current < target {
+        let after = &current + &next;
+        current = std::mem::replace(&mut next, after);
+        offset += 1;
+    }
+    while offset > 1 {
+        let previous = &next - &current;
+        if &previous < target {
+            break;
+        }
+        next = std::mem::replace(&mut current, previous);
+        offset -= 1;
+    }
+
+    offset
+}
+
+/// log₂ of a positive value from its top 64 bits.
+fn log2(value: &BigInt) -> f64 {
+    let shift = value.bits().saturating_sub(64);
+    let top = (value >> shift).to_f64().unwrap_or(f64::MAX);
+    top.log2() + shift as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn range(low: i64, high: i64) -> ValueRange {
+        ValueRange::new(BigInt::from(low), BigInt::from(high)).unwrap()
+    }
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    fn brute_force(start_nums: &(BigInt, BigInt), range: &ValueRange, count: usize) -> Vec<usize> {
+        (0..count)
+            .filter(|&n| range.contains(&nth_term(start_nums, n)))
+            .collect()
+    }
+
+    #[test_case(seeds(0, 1), range(10, 100), &[7, 8, 9, 10, 11]; "classic")]
+    #[test_case(seeds(5, -3), range(-1, 2), &[2, 3, 4, 5, 6, 7, 8]; "zero crossing")]
+    #[test_case(seeds(-1, -1), range(-5, -2), &[2, 3, 4]; "falling")]
+    #[test_case(seeds(50, 1), range(40, 60), &[0, 2, 3]; "large first seed")]
+    #[test_case(seeds(0, 1), range(-10, -1), &[]; "below every term")]
+    #[test_case(seeds(0, 0), range(-1, 1), &[0]; "zero sequence")]
+    fn test_indices(start_nums: (BigInt, BigInt), range: ValueRange, expected: &[usize]) {
+        let indices: Vec<usize> = range.indices(&start_nums).iter().collect();
+        assert_eq!(indices, expected);
+    }
+
+    #[test_case(seeds(0, 1); "classic")]
+    #[test_case(seeds(2, 1); "lucas")]
+    #[test_case(seeds(-17, 5); "mixed")]
+    #[test_case(seeds(1000, -999); "long alternating prefix")]
+    #[test_case(seeds(-3, -8); "negative")]
+    fn test_matches_brute_force(start_nums: (BigInt, BigInt)) {
+        let bounds = [
+            -1_000_000_000_000,
+            -5_000,
+            -1,
+            0,
+            3,
+            144,
+            10_000,
+            1_000_000_000_000,
+        ];
+        for &low in &bounds {
+            for &high in bounds.iter().filter(|&&high| high >= low) {
+                let range = range(low, high);
+                let indices: Vec<usize> = range.indices(&start_nums).iter().collect();
+                assert_eq!(indices, brute_force(&start_nums, &range, 120), "{}", range);
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_values() {
+        let low = BigInt::from(10).pow(1000);
+        let high = BigInt::from(10).pow(1001);
+        let range = ValueRange::new(low, high).unwrap();
+        let start_nums = seeds(0, 1);
+
+        let indices: Vec<usize> = range.indices(&start_nums).iter().collect();
+        assert_eq!(indices, brute_force(&start_nums, &range, 4_800));
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert_eq!(
+            ValueRange::new(BigInt::from(2), BigInt::from(1)),
+            Err(String::from("Value range high must be >= low"))
+        );
+    }
+}
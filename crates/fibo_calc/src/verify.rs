@@ -0,0 +1,361 @@
+use crate::digits;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::ToPrimitive;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// Random primes every term is reduced by.
+pub const VERIFY_PRIMES: usize = 4;
+const PRIME_BITS: u32 = 62;
+/// Streamed terms checked together, see `VerifyStream`.
+const STREAM_CHUNK: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Verified,
+    /// Every check that failed, separated by "; "
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub index: BigInt,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn is_verified(&self) -> bool {
+        self.verdict == Verdict::Verified
+    }
+}
+
+/// Checks terms of one sequence without the generator that produced them:
+/// G(n) mod p by fast doubling, and the generalised Cassini identity
+/// G(n−1)·G(n+1) − G(n)² = (−1)^n·(b² − ab − a²) for consecutive indices.
+#[derive(Debug, Clone)]
+pub struct Verifier {
+    start_nums: (BigInt, BigInt),
+    primes: Vec<u64>,
+}
+
+impl Verifier {
+    pub fn new(start_nums: (BigInt, BigInt)) -> Self {
+        let seed = RandomState::new();
+        let primes = (0..VERIFY_PRIMES as u64)
+            .map(|i| {
+                let mut hasher = seed.build_hasher();
+                hasher.write_u64(i);
+                random_prime(hasher.finish())
+            })
+            .collect();
+        Self::with_primes(start_nums, primes)
+    }
+
+    pub fn with_primes(start_nums: (BigInt, BigInt), primes: Vec<u64>) -> Self {
+        Self { start_nums, primes }
+    }
+
+    pub fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+
+    /// One verdict per term, in order. Cassini is checked at n whenever the
+    /// terms at n − 1 and n + 1 directly surround it.
+    pub fn check<'a>(
+        &self, terms: impl IntoIterator<Item = (usize, &'a BigInt)>,
+    ) -> Vec<Verification> {
+        let terms: Vec<(usize, Vec<u64>)> = terms
+            .into_iter()
+            .map(|(index, value)| (index, self.residues(value)))
+            .collect();
+        self.check_residues(&terms)
+    }
+
+    fn check_residues(&self, terms: &[(usize, Vec<u64>)]) -> Vec<Verification> {
+        let mut verifications = Vec::with_capacity(terms.len());
+        for (position, (index, residues)) in terms.iter().enumerate() {
+            let mut failures = self.residue_failures(&BigInt::from(*index), residues);
+
+            if let (Some(before), Some(after)) = (
+                position.checked_sub(1).map(|i| &terms[i]),
+                terms.get(position + 1),
+            ) && before.0 + 1 == *index
+                && *index + 1 == after.0
+            {
+                failures.extend(self.cassini_failures(*index, &before.1, residues, &after.1));
+            }
+
+            verifications.push(Verification {
+                index: BigInt::from(*index),
+                verdict: verdict(failures),
+            });
+        }
+        verifications
+    }
+
+    /// A single G(n), checked against fast doubling only.
+    pub fn check_one(&self, n: &BigInt, value: &BigInt) -> Verification {
+        let failures = self.residue_failures(n, &self.residues(value));
+        Verification {
+            index: n.clone(),
+            verdict: verdict(failures),
+        }
+    }
+
+    fn residues(&self, value: &BigInt) -> Vec<u64> {
+        self.primes.iter().map(|&p| residue(value, p)).collect()
+    }
+
+    fn residue_failures(&self, n: &BigInt, residues: &[u64]) -> Vec<String> {
+        self.primes
+            .iter()
+            .zip(residues)
+            .filter_map(|(&p, &actual)| {
+                let expected = self.term_mod(n, p);
+                (actual != expected).then(|| {
+                    format!(
+                        "G({}) ≡ {} mod {}, fast doubling gives {}",
+                        n, actual, p, expected
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn cassini_failures(
+        &self, n: usize, before: &[u64], current: &[u64], after: &[u64],
+    ) -> Vec<String> {
+        let (a, b) = &self.start_nums;
+        let constant: BigInt = b * b - a * b - a * a;
+        let constant = if n.is_multiple_of(2) {
+            constant
+        } else {
+            -constant
+        };
+
+        self.primes
+            .iter()
+            .enumerate()
+            .filter(|&(i, &p)| {
+                let lhs = sub_mod(
+                    mul_mod(before[i], after[i], p),
+                    mul_mod(current[i], current[i], p),
+                    p,
+                );
+                lhs != residue(&constant, p)
+            })
+            .map(|(_, p)| format!("Cassini identity fails at n = {} mod {}", n, p))
+            .collect()
+    }
+
+    fn term_mod(&self, n: &BigInt, p: u64) -> u64 {
+        residue(&digits::term_mod(&self.start_nums, n, &BigInt::from(p)), p)
+    }
+}
+
+/// `Verifier::check` for terms that arrive one at a time. Only residues of
+/// the latest chunk are kept, and the last two carry over so Cassini still
+/// sees the neighbours of every term.
+pub(crate) struct VerifyStream {
+    verifier: Verifier,
+    pending: Vec<(usize, Vec<u64>)>,
+    /// The first pending term got its verdict with the previous chunk
+    first_done: bool,
+    verifications: Vec<Verification>,
+}
+
+impl VerifyStream {
+    pub(crate) fn new(verifier: Verifier) -> Self {
+        Self {
+            verifier,
+            pending: Vec::with_capacity(STREAM_CHUNK + 1),
+            first_done: false,
+            verifications: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, index: usize, value: &BigInt) {
+        self.pending.push((index, self.verifier.residues(value)));
+        if self.pending.len() > STREAM_CHUNK {
+            // The last term waits for its successor
+            let checked = self.verifier.check_residues(&self.pending);
+            let skip = usize::from(self.first_done);
+            self.verifications.extend_from_slice(&checked[skip..checked.len() - 1]);
+            self.pending.drain(..self.pending.len() - 2);
+            self.first_done = true;
+        }
+    }
+
+    /// One verdict per pushed term, in order.
+    pub(crate) fn finish(mut self) -> Vec<Verification> {
+        let checked = self.verifier.check_residues(&self.pending);
+        let skip = usize::from(self.first_done).min(checked.len());
+        self.verifications.extend_from_slice(&checked[skip..]);
+        self.verifications
+    }
+}
+
+fn verdict(failures: Vec<String>) -> Verdict {
+    if failures.is_empty() {
+        Verdict::Verified
+    } else {
+        Verdict::Failed(failures.join("; "))
+    }
+}
+
+fn residue(value: &BigInt, p: u64) -> u64 {
+    value
+        .mod_floor(&BigInt::from(p))
+        .to_u64()
+        .unwrap_or_default()
+}
+
+fn mul_mod(x: u64, y: u64, p: u64) -> u64 {
+    ((x as u128 * y as u128) % p as u128) as u64
+}
+
+fn sub_mod(x: u64, y: u64, p: u64) -> u64 {
+    (x + p - y) % p
+}
+
+/// First prime at or after a random odd number of `PRIME_BITS` bits.
+fn random_prime(random: u64) -> u64 {
+    let mut candidate = (random >> (64 - PRIME_BITS)) | (1 << (PRIME_BITS - 1)) | 1;
+    while !is_prime(candidate) {
+        candidate += 2;
+    }
+    candidate
+}
+
+/// Miller–Rabin with the bases that are exact for every u64.
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&base) = BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+
+    let d = (n - 1) >> (n - 1).trailing_zeros();
+    BASES.iter().all(|&base| {
+        let mut x = pow_mod(base, d, n);
+        let mut e = d;
+        while e != n - 1 && x != 1 && x != n - 1 {
+            x = mul_mod(x, x, n);
+            e <<= 1;
+        }
+        x == n - 1 || e & 1 == 1
+    })
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, p: u64) -> u64 {
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementation::matmul::nth_term;
+    use test_case::test_case;
+
+    fn seeds(a: i64, b: i64) -> (BigInt, BigInt) {
+        (BigInt::from(a), BigInt::from(b))
+    }
+
+    fn terms(start_nums: &(BigInt, BigInt), range: std::ops::Range<usize>) -> Vec<BigInt> {
+        range.map(|n| nth_term(start_nums, n)).collect()
+    }
+
+    #[test_case(seeds(0, 1); "classic")]
+    #[test_case(seeds(2, 1); "lucas")]
+    #[test_case(seeds(-17, 5); "mixed")]
+    fn test_correct_terms_verify(start_nums: (BigInt, BigInt)) {
+        let values = terms(&start_nums, 0..300);
+        let verifier = Verifier::new(start_nums);
+
+        let verifications = verifier.check((0..).zip(&values));
+        assert_eq!(verifications.len(), 300);
+        assert!(verifications.iter().all(Verification::is_verified));
+    }
+
+    #[test]
+    fn test_corrupted_term_fails_with_details() {
+        let start_nums = seeds(0, 1);
+        let mut values = terms(&start_nums, 10..20);
+        values[5] += 1;
+        let verifier = Verifier::with_primes(start_nums, vec![1_000_000_007]);
+
+        let verifications = verifier.check((10..).zip(&values));
+        let failed: Vec<&BigInt> = verifications
+            .iter()
+            .filter(|v| !v.is_verified())
+            .map(|v| &v.index)
+            .collect();
+        assert_eq!(
+            failed,
+            [&BigInt::from(14), &BigInt::from(15), &BigInt::from(16)]
+        );
+
+        assert_eq!(
+            verifications[5].verdict,
+            Verdict::Failed(String::from(
+                "G(15) ≡ 611 mod 1000000007, fast doubling gives 610; \
+                 Cassini identity fails at n = 15 mod 1000000007"
+            ))
+        );
+        assert_eq!(
+            verifications[4].verdict,
+            Verdict::Failed(String::from(
+                "Cassini identity fails at n = 14 mod 1000000007"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_check_one() {
+        let start_nums = seeds(2, 1);
+        let n = BigInt::from(5_000);
+        let value = nth_term(&start_nums, 5_000);
+        let verifier = Verifier::new(start_nums);
+
+        assert!(verifier.check_one(&n, &value).is_verified());
+        assert!(!verifier.check_one(&n, &(value + 1)).is_verified());
+    }
+
+    #[test_case(10; "one chunk")]
+    #[test_case(3 * STREAM_CHUNK + 7; "several chunks")]
+    fn test_stream_matches_check(len: usize) {
+        let start_nums = seeds(2, 1);
+        let mut values = terms(&start_nums, 0..len);
+        values[len - 5] += 1;
+        let verifier = Verifier::new(start_nums);
+
+        let mut stream = VerifyStream::new(verifier.clone());
+        for (index, value) in values.iter().enumerate() {
+            stream.push(index, value);
+        }
+        assert_eq!(stream.finish(), verifier.check(values.iter().enumerate()));
+    }
+
+    #[test]
+    fn test_random_primes() {
+        let verifier = Verifier::new(seeds(0, 1));
+        assert_eq!(verifier.primes().len(), VERIFY_PRIMES);
+        for &p in verifier.primes() {
+            assert_eq!(64 - p.leading_zeros(), PRIME_BITS);
+            assert!(is_prime(p));
+        }
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(3_215_031_751)); // strong pseudoprime to 2, 3, 5, 7
+    }
+}
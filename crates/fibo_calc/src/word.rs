@@ -0,0 +1,145 @@
+use crate::implementation::doubling::fibo_pair;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+use std::fmt::Display;
+
+pub const DEFAULT_ALPHABET: (char, char) = ('0', '1');
+
+/// Fibonacci word S₀ = "0", S₁ = "01", Sₙ = Sₙ₋₁Sₙ₋₂ written over `alphabet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiboWord {
+    index: usize,
+    alphabet: (char, char),
+}
+
+impl FiboWord {
+    pub fn new(index: usize, alphabet: (char, char)) -> Self {
+        Self { index, alphabet }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// |Sₙ| = F(n + 2)
+    pub fn len(&self) -> BigInt {
+        let (f_n, f_next) = fibo_pair(&BigInt::from(self.index));
+        f_n + f_next
+    }
+
+    /// Character at `position` without building the word: it is the second
+    /// letter exactly when the Zeckendorf representation of `position` uses F(2).
+    pub fn char_at(&self, position: &BigInt) -> Option<char> {
+        if position.is_negative() || position >= &self.len() {
+            return None;
+        }
+
+        let mut fibs = vec![BigInt::one(), BigInt::from(2)];
+        while fibs.last().is_some_and(|last| last <= position) {
+            let next = &fibs[fibs.len() - 1] + &fibs[fibs.len() - 2];
+            fibs.push(next);
+        }
+
+        let mut rest = position.clone();
+        let mut smallest_used = BigInt::zero();
+        for fib in fibs.iter().rev() {
+            if fib <= &rest {
+                rest -= fib;
+                smallest_used = fib.clone();
+            }
+        }
+
+        Some(if smallest_used.is_one() {
+            self.alphabet.1
+        } else {
+            self.alphabet.0
+        })
+    }
+
+    /// Streams the characters in order, keeping only O(n) state.
+    pub fn chars(&self) -> WordChars {
+        WordChars {
+            stack: vec![self.index as isize],
+            alphabet: self.alphabet,
+        }
+    }
+
+    pub fn prefix(&self, k: usize) -> String {
+        self.chars().take(k).collect()
+    }
+}
+
+impl Display for FiboWord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.chars().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+/// Expands Sₙ = Sₙ₋₁Sₙ₋₂ on a stack, with S₋₁ = "1" so that S₁ = S₀S₋₁.
+pub struct WordChars {
+    stack: Vec<isize>,
+    alphabet: (char, char),
+}
+
+impl Iterator for WordChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.pop()? {
+                -1 => return Some(self.alphabet.1),
+                0 => return Some(self.alphabet.0),
+                n => {
+                    self.stack.push(n - 2);
+                    self.stack.push(n - 1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, "0"; "zero")]
+    #[test_case(1, "01"; "one")]
+    #[test_case(2, "010"; "two")]
+    #[test_case(5, "0100101001001"; "five")]
+    fn test_words(index: usize, expected: &str) {
+        let word = FiboWord::new(index, DEFAULT_ALPHABET);
+        assert_eq!(word.to_string(), expected);
+        assert_eq!(word.len(), BigInt::from(expected.len()));
+    }
+
+    #[test]
+    fn test_recurrence_and_alphabet() {
+        let alphabet = ('a', 'b');
+        for n in 2..15 {
+            let word = FiboWord::new(n, alphabet).to_string();
+            let expected = FiboWord::new(n - 1, alphabet).to_string()
+                + &FiboWord::new(n - 2, alphabet).to_string();
+            assert_eq!(word, expected);
+        }
+    }
+
+    #[test]
+    fn test_char_at_matches_stream() {
+        let word = FiboWord::new(20, ('a', 'b'));
+        for (position, c) in word.chars().enumerate() {
+            assert_eq!(word.char_at(&BigInt::from(position)), Some(c));
+        }
+        assert_eq!(word.char_at(&word.len()), None);
+        assert_eq!(word.char_at(&BigInt::from(-1)), None);
+    }
+
+    #[test]
+    fn test_huge_word_is_lazy() {
+        let word = FiboWord::new(10_000, DEFAULT_ALPHABET);
+        assert_eq!(word.prefix(13), "0100101001001");
+        assert_eq!(word.len().bits(), 6_943);
+        let position = BigInt::from(10u64).pow(300);
+        assert!(word.char_at(&position).is_some());
+    }
+}
@@ -1,5 +1,6 @@
 extern crate winres;
 
+#[cfg(target_os = "windows")]
 const PATH_TO_ICON: &str = "../../resources/logo.ico";
 
 fn main() {
@@ -74,6 +74,11 @@ impl EventHandler {
                 self.state.input.range_end.clear();
                 Ok(false)
             }
+            'S' => {
+                self.state.input_mode = InputMode::Selection;
+                self.state.input.selection.clear();
+                Ok(false)
+            }
             'v' => {
                 self.state.input_mode = InputMode::FilterValue;
                 self.state.input.filter_value.clear();
@@ -91,7 +96,7 @@ impl EventHandler {
                 self.state
                     .add_filter()
                     .await
-                    .map_err(|e| Error::new(std::io::ErrorKind::Other, e))?;
+                    .map_err(Error::other)?;
                 Ok(false)
             }
             'r' => {
@@ -106,6 +111,75 @@ impl EventHandler {
                 self.state.delete_filter();
                 Ok(false)
             }
+            'p' => {
+                self.state.toggle_ratio_column();
+                Ok(false)
+            }
+            'm' => {
+                self.state.next_mode();
+                Ok(false)
+            }
+            'h' => {
+                self.state.input_mode = InputMode::HugeIndex;
+                self.state.input.huge_index.clear();
+                Ok(false)
+            }
+            'u' => {
+                self.state.query_huge_index().await;
+                Ok(false)
+            }
+            't' => {
+                self.state.input_mode = InputMode::Terms;
+                self.state.input.terms.clear();
+                Ok(false)
+            }
+            'x' => {
+                self.state.next_transform();
+                Ok(false)
+            }
+            'k' => {
+                self.state.input_mode = InputMode::CompareSeeds;
+                self.state.input.compare_seeds.clear();
+                Ok(false)
+            }
+            'b' => {
+                self.state.input_mode = InputMode::ValueBound;
+                self.state.input.value_bound.clear();
+                Ok(false)
+            }
+            'f' => {
+                self.state.input_mode = InputMode::FindCount;
+                self.state.input.find_count.clear();
+                Ok(false)
+            }
+            'o' => {
+                self.state.next_set_operation();
+                Ok(false)
+            }
+            'i' => {
+                self.state.identify_terms();
+                Ok(false)
+            }
+            'y' => {
+                self.state.toggle_by_value();
+                Ok(false)
+            }
+            'w' => {
+                self.state.toggle_verify();
+                Ok(false)
+            }
+            'n' => {
+                self.state.resume_interrupted();
+                Ok(false)
+            }
+            'j' => {
+                self.state.discard_interrupted();
+                Ok(false)
+            }
+            'z' => {
+                self.state.next_display_format();
+                Ok(false)
+            }
             _ => Ok(false),
         }
     }
@@ -118,10 +192,12 @@ impl EventHandler {
             }
             KeyCode::Char(c) => {
                 self.handle_input_char(c).await;
+                self.refresh_closed_form().await;
                 Ok(false)
             }
             KeyCode::Backspace => {
                 self.handle_backspace().await;
+                self.refresh_closed_form().await;
                 Ok(false)
             }
             _ => Ok(false),
@@ -142,13 +218,25 @@ impl EventHandler {
         }
     }
 
+    async fn refresh_closed_form(&mut self) {
+        if matches!(self.state.input_mode, InputMode::Start1 | InputMode::Start2) {
+            self.state.update_closed_form().await;
+        }
+    }
+
     async fn get_current_input_field(&mut self) -> Option<&mut String> {
         match self.state.input_mode {
             InputMode::Start1 => Some(&mut self.state.input.start1),
             InputMode::Start2 => Some(&mut self.state.input.start2),
             InputMode::RangeStart => Some(&mut self.state.input.range_start),
             InputMode::RangeEnd => Some(&mut self.state.input.range_end),
+            InputMode::Selection => Some(&mut self.state.input.selection),
             InputMode::FilterValue => Some(&mut self.state.input.filter_value),
+            InputMode::HugeIndex => Some(&mut self.state.input.huge_index),
+            InputMode::Terms => Some(&mut self.state.input.terms),
+            InputMode::CompareSeeds => Some(&mut self.state.input.compare_seeds),
+            InputMode::ValueBound => Some(&mut self.state.input.value_bound),
+            InputMode::FindCount => Some(&mut self.state.input.find_count),
             InputMode::Normal => None,
         }
     }
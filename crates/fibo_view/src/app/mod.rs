@@ -7,6 +7,8 @@ use crate::ui;
 use ratatui::DefaultTerminal;
 use std::io;
 
+const CHECKPOINT_DIR: &str = "fibo_view_checkpoints";
+
 pub struct TerminalApp {
     terminal: DefaultTerminal,
     state: AppState,
@@ -20,9 +22,12 @@ impl Default for TerminalApp {
 
 impl TerminalApp {
     pub fn new() -> Self {
+        let mut state = AppState::new();
+        state.use_checkpoint_dir(std::env::temp_dir().join(CHECKPOINT_DIR));
+
         Self {
             terminal: ratatui::init(),
-            state: AppState::new(),
+            state,
         }
     }
 
@@ -1,8 +1,10 @@
+use fibo_calc::{IndexSelection, ValueRange};
 use num_bigint::BigInt;
 
 pub struct CalculationParams {
     pub start1: BigInt,
     pub start2: BigInt,
-    pub range_start: usize,
-    pub range_end: usize,
+    pub selection: IndexSelection,
+    /// Set in by-value mode, replaces the selection
+    pub value_range: Option<ValueRange>,
 }
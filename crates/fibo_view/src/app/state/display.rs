@@ -0,0 +1,58 @@
+use fibo_calc::{Grouping, Notation, NumberFormat};
+use std::fmt::Display;
+
+/// Significant digits of the scientific format.
+const SCIENTIFIC_DIGITS: usize = 12;
+
+/// How the output panel writes numbers, cycled with [z].
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub enum DisplayFormat {
+    #[default]
+    Decimal,
+    Grouped,
+    Scientific,
+    Hexadecimal,
+    Binary,
+}
+
+impl DisplayFormat {
+    pub fn next(self) -> Self {
+        match self {
+            DisplayFormat::Decimal => DisplayFormat::Grouped,
+            DisplayFormat::Grouped => DisplayFormat::Scientific,
+            DisplayFormat::Scientific => DisplayFormat::Hexadecimal,
+            DisplayFormat::Hexadecimal => DisplayFormat::Binary,
+            DisplayFormat::Binary => DisplayFormat::Decimal,
+        }
+    }
+
+    /// Cut to `width` characters in the middle; decimal digits are grouped
+    /// with the separator of the user's locale.
+    pub fn number_format(self, width: usize) -> NumberFormat {
+        let mut format = NumberFormat::default();
+        // Every setting below is in range, so none of them can fail
+        let _ = format.set_max_width(Some(width.max(3)));
+        let _ = match self {
+            DisplayFormat::Decimal => Ok(&mut format),
+            DisplayFormat::Grouped => format.set_grouping(Some(Grouping::from_env(3))),
+            DisplayFormat::Scientific => format.set_notation(Notation::Scientific {
+                significant: SCIENTIFIC_DIGITS,
+            }),
+            DisplayFormat::Hexadecimal => format.set_base(16),
+            DisplayFormat::Binary => format.set_base(2),
+        };
+        format
+    }
+}
+
+impl Display for DisplayFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            DisplayFormat::Decimal => "decimal",
+            DisplayFormat::Grouped => "grouped",
+            DisplayFormat::Scientific => "scientific",
+            DisplayFormat::Hexadecimal => "hex",
+            DisplayFormat::Binary => "binary",
+        })
+    }
+}
@@ -7,18 +7,13 @@ pub struct Filter {
     pub value: BigInt,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub enum FilterType {
+    #[default]
     Ge,
     Le,
 }
 
-impl Default for FilterType {
-    fn default() -> Self {
-        Self::Ge
-    }
-}
-
 impl Display for FilterType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", match self {
@@ -3,6 +3,11 @@ const DEFAULT_START2: &str = "1";
 const DEFAULT_RANGE_START: &str = "0";
 const DEFAULT_RANGE_END: &str = "20";
 const DEFAULT_FILTER_VALUE: &str = "10";
+const DEFAULT_HUGE_INDEX: &str = "1000000000000000000";
+const DEFAULT_TERMS: &str = "2, 1, 3, 4, 7, 11";
+const DEFAULT_COMPARE_SEEDS: &str = "2, 1";
+const DEFAULT_VALUE_BOUND: &str = "1000000";
+const DEFAULT_FIND_COUNT: &str = "10";
 
 #[derive(PartialEq)]
 pub enum InputMode {
@@ -11,7 +16,13 @@ pub enum InputMode {
     Start2,
     RangeStart,
     RangeEnd,
+    Selection,
     FilterValue,
+    HugeIndex,
+    Terms,
+    CompareSeeds,
+    ValueBound,
+    FindCount,
 }
 
 pub struct InputFields {
@@ -19,7 +30,14 @@ pub struct InputFields {
     pub start2: String,
     pub range_start: String,
     pub range_end: String,
+    /// Indices like `5, 10, 100..110 step 2`, used instead of the range when set
+    pub selection: String,
     pub filter_value: String,
+    pub huge_index: String,
+    pub terms: String,
+    pub compare_seeds: String,
+    pub value_bound: String,
+    pub find_count: String,
 }
 
 
@@ -30,7 +48,13 @@ impl Default for InputFields {
             start2: DEFAULT_START2.to_string(),
             range_start: DEFAULT_RANGE_START.to_string(),
             range_end: DEFAULT_RANGE_END.to_string(),
+            selection: String::new(),
             filter_value: DEFAULT_FILTER_VALUE.to_string(),
+            huge_index: DEFAULT_HUGE_INDEX.to_string(),
+            terms: DEFAULT_TERMS.to_string(),
+            compare_seeds: DEFAULT_COMPARE_SEEDS.to_string(),
+            value_bound: DEFAULT_VALUE_BOUND.to_string(),
+            find_count: DEFAULT_FIND_COUNT.to_string(),
         }
     }
 }
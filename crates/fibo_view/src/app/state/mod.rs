@@ -1,19 +1,33 @@
 mod calculation_params;
+mod display;
 mod filter;
 mod input;
+mod mode;
 mod output;
+mod transform;
 
 use crate::domain;
 use calculation_params::CalculationParams;
-use fibo_calc::FiboTaskResult;
+use fibo_calc::{
+    Checkpoint, CheckpointJob, ClosedForm, DEFAULT_INDEX_LIMIT, DigitSummary, FiboTaskResult,
+    FirstMatches, IndexSelection, LinearRecurrence, PolynomialKind, SetOperation, ValueRange,
+};
 pub use filter::FilterType;
 pub use filter::{Filter, FilterState};
 pub use input::{InputFields, InputMode};
+pub use mode::CalcMode;
+pub use transform::TransformChoice;
 use num_bigint::BigInt;
 use output::OutputState;
+pub use display::DisplayFormat;
+use std::path::PathBuf;
 
 const PADDING_SCROLLING: usize = 1;
 const SPEED_SCROLLING: usize = 1;
+const HUGE_INDEX_DIGITS: usize = 10;
+/// Word rows are cut by the output panel anyway, so there is no point in more.
+const WORD_PREFIX_LEN: usize = 48;
+const CHECKPOINT_EXTENSION: &str = "checkpoint";
 
 
 pub struct AppState {
@@ -21,7 +35,24 @@ pub struct AppState {
     pub filters: FilterState,
     pub output: OutputState,
     pub input_mode: InputMode,
+    pub mode: CalcMode,
+    pub transform: TransformChoice,
+    pub set_operation: SetOperation,
+    /// Range Start/End hold a value interval instead of indices
+    pub by_value: bool,
+    /// Numbers runs send their terms through `fibo_calc::Verifier`
+    pub verify: bool,
+    /// Numbers runs save their progress in this directory when set, each
+    /// to a file of its own
+    pub checkpoint_dir: Option<PathBuf>,
+    /// Runs started so far, which numbers their checkpoint files
+    pub checkpoint_runs: usize,
+    /// Checkpoint of a run an earlier session did not finish, with its path
+    pub resume_offer: Option<(PathBuf, Checkpoint)>,
     pub count_use: usize,
+    pub huge_index: Option<DigitSummary>,
+    pub recurrence: Option<LinearRecurrence>,
+    pub closed_form: Option<ClosedForm>,
     pub error: Option<String>,
 }
 
@@ -32,7 +63,18 @@ impl Default for AppState {
             filters: FilterState::default(),
             output: OutputState::default(),
             input_mode: InputMode::Normal,
+            mode: CalcMode::default(),
+            transform: TransformChoice::default(),
+            set_operation: SetOperation::default(),
+            by_value: false,
+            verify: false,
+            checkpoint_dir: None,
+            checkpoint_runs: 0,
+            resume_offer: None,
             count_use: 0,
+            huge_index: None,
+            recurrence: None,
+            closed_form: None,
             error: None,
         }
     }
@@ -42,9 +84,90 @@ impl AppState {
     pub fn new() -> Self {
         let mut state = Self::default();
         state.output.list_state.select(Some(0));
+        state.closed_form = state
+            .start_nums_literal()
+            .map(|start_nums| domain::closed_form(&start_nums));
         state
     }
 
+    /// Checkpoints Numbers runs into `dir` and offers to resume the newest
+    /// checkpoint another process left there, if any.
+    pub fn use_checkpoint_dir(&mut self, dir: PathBuf) {
+        let _ = std::fs::create_dir_all(&dir);
+        let own_prefix = format!("{}-", std::process::id());
+        self.resume_offer = std::fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == CHECKPOINT_EXTENSION))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| !name.starts_with(&own_prefix))
+            })
+            .filter_map(|path| {
+                let modified = path.metadata().and_then(|meta| meta.modified()).ok()?;
+                let checkpoint = Checkpoint::load(&path).ok()?;
+                Some((modified, path, checkpoint))
+            })
+            .max_by_key(|(modified, ..)| *modified)
+            .map(|(_, path, checkpoint)| (path, checkpoint));
+        self.checkpoint_dir = Some(dir);
+    }
+
+    /// A fresh file for the next run, so runs that overlap never share one.
+    fn next_checkpoint_path(&mut self) -> Option<PathBuf> {
+        let dir = self.checkpoint_dir.as_ref()?;
+        self.checkpoint_runs += 1;
+        Some(dir.join(format!(
+            "{}-{}.{}",
+            std::process::id(),
+            self.checkpoint_runs,
+            CHECKPOINT_EXTENSION
+        )))
+    }
+
+    pub fn resume_interrupted(&mut self) {
+        let Some((path, _)) = self.resume_offer.take() else {
+            return;
+        };
+
+        self.count_use += 1;
+        self.output.search = None;
+        self.output.verification = None;
+        match domain::resume_calculation(
+            &path,
+            &self.filters.filters,
+            self.transform.to_transform(),
+            self.verify,
+        ) {
+            Ok(receiver) => self.output.receiver = Some(receiver),
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    pub fn discard_interrupted(&mut self) {
+        if let Some((path, _)) = self.resume_offer.take() {
+            Checkpoint::remove(path);
+        }
+    }
+
+    /// Short description of the offered job for the input panel.
+    pub fn resume_offer_text(&self) -> Option<String> {
+        let (_, checkpoint) = self.resume_offer.as_ref()?;
+        Some(match &checkpoint.job {
+            CheckpointJob::Range { range, next, .. } => format!(
+                "indices {}..{}, {} of {} done",
+                range.start,
+                range.end,
+                next - range.start,
+                range.len()
+            ),
+            CheckpointJob::One { n, .. } => format!("term {}", n),
+        })
+    }
+
     pub async fn add_filter(&mut self) -> Result<(), String> {
         let value = domain::calculate_expr(&self.input.filter_value).await?;
 
@@ -63,15 +186,119 @@ impl AppState {
         self.filters.filters.clear();
     }
 
+    pub fn next_mode(&mut self) {
+        self.mode = self.mode.next();
+    }
+
+    pub fn next_transform(&mut self) {
+        self.transform = self.transform.next();
+    }
+
+    pub fn next_set_operation(&mut self) {
+        self.set_operation = match self.set_operation {
+            SetOperation::Intersection => SetOperation::Difference,
+            SetOperation::Difference => SetOperation::Union,
+            SetOperation::Union => SetOperation::Intersection,
+        };
+    }
+
+    pub fn toggle_by_value(&mut self) {
+        self.by_value = !self.by_value;
+    }
+
+    pub fn toggle_verify(&mut self) {
+        self.verify = !self.verify;
+    }
+
+    pub fn next_display_format(&mut self) {
+        self.output.next_format();
+    }
+
+    pub fn toggle_ratio_column(&mut self) {
+        self.output.toggle_ratio();
+    }
+
+    /// Fits a recurrence to the pasted terms and, when they follow the
+    /// Fibonacci rule, copies their start numbers into the input fields.
+    pub fn identify_terms(&mut self) {
+        let terms: Result<Vec<BigInt>, String> = self
+            .input
+            .terms
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|term| !term.is_empty())
+            .map(|term| {
+                term.parse()
+                    .map_err(|_| format!("Invalid term '{}'", term))
+            })
+            .collect();
+
+        let terms = match terms {
+            Ok(terms) if terms.len() >= 2 => terms,
+            Ok(_) => {
+                self.error = Some(String::from("Paste at least two terms"));
+                return;
+            }
+            Err(e) => {
+                self.error = Some(e);
+                return;
+            }
+        };
+
+        let recurrence = domain::identify_recurrence(&terms);
+        if !recurrence.is_determined() {
+            self.recurrence = None;
+            self.error = Some(format!(
+                "An order {} fit needs at least {} terms, got {}",
+                recurrence.order(),
+                2 * recurrence.order(),
+                terms.len()
+            ));
+            return;
+        }
+
+        if let Some(start_nums) = recurrence.fibonacci_start() {
+            self.input.start1 = start_nums.0.to_string();
+            self.input.start2 = start_nums.1.to_string();
+            self.closed_form = Some(domain::closed_form(start_nums));
+        }
+        self.recurrence = Some(recurrence);
+    }
+
+    /// Recomputed while Start Number 1 or 2 is edited. Half typed input keeps
+    /// the last valid form instead of reporting an error on every key.
+    pub async fn update_closed_form(&mut self) {
+        let start_nums = match self.start_nums_literal() {
+            Some(start_nums) => Some(start_nums),
+            None => match (
+                domain::calculate_expr(&self.input.start1).await,
+                domain::calculate_expr(&self.input.start2).await,
+            ) {
+                (Ok(a), Ok(b)) => Some((BigInt::from(a), BigInt::from(b))),
+                _ => None,
+            },
+        };
+
+        if let Some(start_nums) = start_nums {
+            self.closed_form = Some(domain::closed_form(&start_nums));
+        }
+    }
+
+    /// Start numbers written as plain integers, which may be negative.
+    fn start_nums_literal(&self) -> Option<(BigInt, BigInt)> {
+        let start1 = self.input.start1.trim().parse().ok()?;
+        let start2 = self.input.start2.trim().parse().ok()?;
+        Some((start1, start2))
+    }
+
     pub fn scroll_results(&mut self, direction: i32) {
-        if self.output.results.is_empty() {
+        if self.output.is_empty() {
             return;
         }
 
         let selected = self.output.list_state.selected().unwrap_or(0);
 
         let new_selected = match direction {
-            1 => (selected + 1).min(self.output.results.len() - 1),
+            1 => (selected + 1).min(self.output.len() - 1),
             -1 => selected.saturating_sub(1),
             _ => selected,
         };
@@ -81,7 +308,7 @@ impl AppState {
     }
 
     fn update_viewport(&mut self, selected_index: usize, direction: i32) {
-        let total_items = self.output.results.len();
+        let total_items = self.output.len();
 
         if self.output.viewport_size <= SPEED_SCROLLING + 1 {
             return;
@@ -112,61 +339,281 @@ impl AppState {
         if let Ok(msg) = receiver.try_recv() {
             match msg {
                 FiboTaskResult::Calculation(progress) => {
-                    self.output.results.clear();
+                    self.output.clear();
                     self.output.progress = Some(progress);
                     self.output.viewport_start = 0;
                 }
                 FiboTaskResult::Result(res) => {
-                    self.output.results = res;
+                    self.output.set_results(res);
                     self.output.list_state.select(Some(0));
                     self.output.progress = None;
                     self.output.viewport_start = 0;
                 }
+                FiboTaskResult::Aggregate(summary) => {
+                    self.output.summary = Some(summary);
+                    self.output.progress = None;
+                }
+                FiboTaskResult::Search(progress) => {
+                    self.output.clear();
+                    self.output.search = Some(progress);
+                    self.output.viewport_start = 0;
+                }
+                FiboTaskResult::Verification(verifications) => {
+                    self.output.verification = Some(verifications);
+                }
+                FiboTaskResult::Written(written) => {
+                    self.output.progress = None;
+                    self.error = written.err();
+                }
+                FiboTaskResult::Polynomials(polynomials) => {
+                    self.output.progress = None;
+                    match polynomials {
+                        Ok(polynomials) => {
+                            let texts = polynomials.iter().map(ToString::to_string).collect();
+                            self.output.set_texts(texts);
+                            self.output.list_state.select(Some(0));
+                            self.output.viewport_start = 0;
+                        }
+                        Err(e) => self.error = Some(e),
+                    }
+                }
+                FiboTaskResult::CheckpointFailed(e) => self.error = Some(e),
             }
         }
     }
 
     pub async fn calculate(&mut self) {
         self.count_use += 1;
+        self.output.search = None;
+        self.output.verification = None;
+
+        let kind = match self.mode {
+            CalcMode::Numbers => None,
+            CalcMode::FibonacciPolynomials => Some(PolynomialKind::Fibonacci),
+            CalcMode::LucasPolynomials => Some(PolynomialKind::Lucas),
+            CalcMode::FibonacciWords => {
+                self.calculate_words().await;
+                return;
+            }
+            CalcMode::Compare => {
+                self.calculate_comparison().await;
+                return;
+            }
+            CalcMode::FindFirst => {
+                self.calculate_first_matches().await;
+                return;
+            }
+        };
+        if let Some(kind) = kind {
+            self.calculate_polynomials(kind).await;
+            return;
+        }
 
         let calculation_params = match self.parse_calculation_parameters().await {
             Ok(params) => params,
             Err(_) => return,
         };
 
-        if calculation_params.range_end <= calculation_params.range_start {
-            self.error = Some("Range end must be > start".to_string());
+        let checkpoint = self.next_checkpoint_path();
+        self.output.receiver = Some(
+            domain::calculate_fibonacci(
+                (calculation_params.start1, calculation_params.start2),
+                calculation_params.selection,
+                calculation_params.value_range,
+                &self.filters.filters,
+                self.transform.to_transform(),
+                self.verify,
+                checkpoint,
+            )
+            .await,
+        );
+    }
+
+    pub async fn query_huge_index(&mut self) {
+        let Ok(start1) = self.parse_expr_as_bigint(&self.input.start1.clone()).await else {
+            return;
+        };
+        let Ok(start2) = self.parse_expr_as_bigint(&self.input.start2.clone()).await else {
             return;
+        };
+
+        let index = match self.input.huge_index.trim().parse::<BigInt>() {
+            Ok(index) => index,
+            Err(_) => match self.parse_expr_as_bigint(&self.input.huge_index.clone()).await {
+                Ok(index) => index,
+                Err(_) => return,
+            },
+        };
+
+        match domain::query_huge_index((start1, start2), index, HUGE_INDEX_DIGITS) {
+            Ok(summary) => self.huge_index = Some(summary),
+            Err(e) => {
+                self.huge_index = None;
+                self.error = Some(e);
+            }
         }
+    }
 
+    async fn calculate_polynomials(&mut self, kind: PolynomialKind) {
+        let Ok(selection) = self.parse_index_selection().await else {
+            return;
+        };
+
+        self.output.clear();
+        self.output.progress = None;
+        self.output.receiver = Some(domain::calculate_polynomials(
+            kind,
+            selection,
+            &self.filters.filters,
+        ));
+    }
+
+    async fn calculate_words(&mut self) {
+        let Ok(selection) = self.parse_index_selection().await else {
+            return;
+        };
+
+        self.output.clear();
+        self.output.receiver = None;
+        self.output.progress = None;
+        self.output.set_texts(domain::calculate_words(selection, WORD_PREFIX_LEN));
+        self.output.list_state.select(Some(0));
+        self.output.viewport_start = 0;
+    }
+
+    /// Scans from Range Start with no end until Find First terms pass the filters.
+    async fn calculate_first_matches(&mut self) {
+        let Ok(start1) = self.parse_expr_as_bigint(&self.input.start1.clone()).await else {
+            return;
+        };
+        let Ok(start2) = self.parse_expr_as_bigint(&self.input.start2.clone()).await else {
+            return;
+        };
+        let Ok(start) = self.parse_expr_as_usize(&self.input.range_start.clone()).await else {
+            return;
+        };
+        let Ok(count) = self.parse_expr_as_usize(&self.input.find_count.clone()).await else {
+            return;
+        };
+
+        let search = FirstMatches {
+            count,
+            start,
+            index_limit: start.saturating_add(DEFAULT_INDEX_LIMIT),
+        };
         self.output.receiver = Some(
-            domain::calculate_fibonacci(
-                (calculation_params.start1, calculation_params.start2),
-                calculation_params.range_start..calculation_params.range_end,
+            domain::find_first_matches(
+                (start1, start2),
+                search,
                 &self.filters.filters,
+                self.transform.to_transform(),
             )
             .await,
         );
     }
 
+    async fn calculate_comparison(&mut self) {
+        let Ok(start1) = self.parse_expr_as_bigint(&self.input.start1.clone()).await else {
+            return;
+        };
+        let Ok(start2) = self.parse_expr_as_bigint(&self.input.start2.clone()).await else {
+            return;
+        };
+        let compare_seeds = self.input.compare_seeds.clone();
+        let Some((compare1, compare2)) = compare_seeds.split_once(',') else {
+            self.error = Some(String::from("Compare seeds must look like 'a, b'"));
+            return;
+        };
+        let Ok(compare1) = self.parse_expr_as_bigint(compare1).await else {
+            return;
+        };
+        let Ok(compare2) = self.parse_expr_as_bigint(compare2).await else {
+            return;
+        };
+        let Ok(bound) = self.parse_expr_as_bigint(&self.input.value_bound.clone()).await else {
+            return;
+        };
+
+        self.output.clear();
+        self.output.receiver = None;
+        self.output.progress = None;
+        self.output.set_results(domain::compare_sequences(
+            (start1, start2),
+            (compare1, compare2),
+            &self.filters.filters,
+            self.set_operation,
+            &bound,
+        ));
+        self.output.list_state.select(Some(0));
+        self.output.viewport_start = 0;
+    }
+
     async fn parse_calculation_parameters(&mut self) -> Result<CalculationParams, ()> {
+        let start1 = self
+            .parse_expr_as_bigint(&self.input.start1.clone())
+            .await?;
+        let start2 = self
+            .parse_expr_as_bigint(&self.input.start2.clone())
+            .await?;
+
+        if self.by_value {
+            return Ok(CalculationParams {
+                start1,
+                start2,
+                selection: IndexSelection::default(),
+                value_range: Some(self.parse_value_range().await?),
+            });
+        }
+
         Ok(CalculationParams {
-            start1: self
-                .parse_expr_as_bigint(&self.input.start1.clone())
-                .await?,
-            start2: self
-                .parse_expr_as_bigint(&self.input.start2.clone())
-                .await?,
-            range_start: self
-                .parse_expr_as_usize(&self.input.range_start.clone())
-                .await?,
-            range_end: self
-                .parse_expr_as_usize(&self.input.range_end.clone())
-                .await?,
+            start1,
+            start2,
+            selection: self.parse_index_selection().await?,
+            value_range: None,
         })
     }
 
+    async fn parse_value_range(&mut self) -> Result<ValueRange, ()> {
+        let low = self
+            .parse_expr_as_bigint(&self.input.range_start.clone())
+            .await?;
+        let high = self
+            .parse_expr_as_bigint(&self.input.range_end.clone())
+            .await?;
+
+        ValueRange::new(low, high).map_err(|e| {
+            self.error = Some(e);
+        })
+    }
+
+    /// The Selection field when set, otherwise Range Start to Range End.
+    async fn parse_index_selection(&mut self) -> Result<IndexSelection, ()> {
+        let selection = self.input.selection.trim().to_string();
+        if !selection.is_empty() {
+            return selection.parse().map_err(|e| {
+                self.error = Some(e);
+            });
+        }
+
+        let start = self
+            .parse_expr_as_usize(&self.input.range_start.clone())
+            .await?;
+        let end = self
+            .parse_expr_as_usize(&self.input.range_end.clone())
+            .await?;
+
+        if end <= start {
+            self.error = Some("Range end must be > start".to_string());
+            return Err(());
+        }
+        Ok(IndexSelection::range(start..end))
+    }
+
     async fn parse_expr_as_bigint(&mut self, input: &str) -> Result<BigInt, ()> {
+        if let Ok(value) = input.trim().parse::<BigInt>() {
+            return Ok(value);
+        }
+
         match domain::calculate_expr(input).await {
             Ok(value) => Ok(BigInt::from(value)),
             Err(e) => {
@@ -190,8 +637,9 @@ impl AppState {
 
 #[cfg(test)]
 mod tests {
+    use super::output::OutputRow;
     use super::*;
-    use fibo_calc::FiboTaskResult;
+    use fibo_calc::{FiboAggregate, FiboTaskResult, SearchProgress, Verifier};
     use test_case::test_case;
     use tokio::sync::mpsc;
 
@@ -200,12 +648,23 @@ mod tests {
         results: Vec<BigInt>, viewport_start: usize, viewport_size: usize,
     ) -> AppState {
         let mut state = AppState::default();
-        state.output.results = results;
+        state.output.set_results(results);
         state.output.viewport_start = viewport_start;
         state.output.viewport_size = viewport_size;
         state
     }
 
+    /// Feeds every message of the running task through `update_progress_bar`.
+    async fn run_to_end(state: &mut AppState) {
+        let mut receiver = state.output.receiver.take().expect("No receiver");
+        let (sender, relay) = mpsc::unbounded_channel();
+        state.output.receiver = Some(relay);
+        while let Some(msg) = receiver.recv().await {
+            sender.send(msg).unwrap();
+            state.update_progress_bar();
+        }
+    }
+
     #[test_case(SPEED_SCROLLING, 2, 1 => 0; "viewport too small")]
     #[test_case(10, 9, 1 => SPEED_SCROLLING; "scroll down")]
     #[test_case(10, SPEED_SCROLLING * 2, -1 => SPEED_SCROLLING; "scroll up")]
@@ -287,6 +746,69 @@ mod tests {
         assert!(state.filters.filters.is_empty());
     }
 
+    #[test]
+    fn test_toggle_ratio_column() {
+        let mut state = AppState::new();
+        assert!(!state.output.show_ratio);
+        state.toggle_ratio_column();
+        assert!(state.output.show_ratio);
+        state.toggle_ratio_column();
+        assert!(!state.output.show_ratio);
+    }
+
+    #[test]
+    fn test_rows_rendered_once_per_results() {
+        let mut state = AppState::new();
+        let huge = BigInt::from(-7) * BigInt::from(10).pow(300);
+        state.output.set_results(vec![BigInt::from(-3), huge]);
+        state.output.prepare_visible_rows();
+
+        assert_eq!(state.output.row_text(0), "-3");
+        assert_eq!(
+            state.output.row_text(1),
+            format!("-7{}…{}", "0".repeat(23), "0".repeat(24))
+        );
+
+        state.toggle_ratio_column();
+        state.output.prepare_visible_rows();
+        assert!(state.output.row_text(1).contains("  φ≈"));
+    }
+
+    #[test_case(BigInt::from(-123_456), 15, DisplayFormat::Decimal => "-123456"; "fits")]
+    #[test_case(BigInt::from(10).pow(15), 15, DisplayFormat::Decimal => "1000000…0000000";
+        "one digit too many")]
+    #[test_case(-BigInt::from(10).pow(14), 15, DisplayFormat::Decimal => "-1000000…000000";
+        "sign counts")]
+    #[test_case(BigInt::from(832_040), 15, DisplayFormat::Scientific => "8.32040e5"; "scientific")]
+    #[test_case(BigInt::from(-1_234_567), 15, DisplayFormat::Hexadecimal => "-0x12d687"; "hex")]
+    #[test_case(BigInt::from(1) << 20, 15, DisplayFormat::Binary => "0b100000…000000"; "binary")]
+    fn test_format_value(num: BigInt, width: usize, format: DisplayFormat) -> String {
+        let mut state = AppState::new();
+        while state.output.format != format {
+            state.next_display_format();
+        }
+        state.output.format_value(&num, width)
+    }
+
+    #[test]
+    fn test_display_format_cycle() {
+        let mut state = AppState::new();
+        state.output.set_results(vec![BigInt::from(255)]);
+        state.output.prepare_visible_rows();
+        assert_eq!(state.output.row_text(0), "255");
+
+        state.next_display_format();
+        state.next_display_format();
+        state.next_display_format();
+        assert_eq!(state.output.format, DisplayFormat::Hexadecimal);
+        state.output.prepare_visible_rows();
+        assert_eq!(state.output.row_text(0), "0xff");
+
+        state.next_display_format();
+        state.next_display_format();
+        assert_eq!(state.output.format, DisplayFormat::Decimal);
+    }
+
     #[test]
     fn test_scroll_results_empty() {
         let mut state = AppState::new();
@@ -297,7 +819,7 @@ mod tests {
     #[test]
     fn test_scroll_results_forward() {
         let mut state = AppState::new();
-        state.output.results = vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)];
+        state.output.set_results(vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)]);
         state.output.list_state.select(Some(0));
 
         state.scroll_results(1);
@@ -307,7 +829,7 @@ mod tests {
     #[test]
     fn test_scroll_results_backward() {
         let mut state = AppState::new();
-        state.output.results = vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)];
+        state.output.set_results(vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)]);
         state.output.list_state.select(Some(1));
 
         state.scroll_results(-1);
@@ -317,7 +839,7 @@ mod tests {
     #[test]
     fn test_scroll_results_bounds() {
         let mut state = AppState::new();
-        state.output.results = vec![BigInt::from(1), BigInt::from(2)];
+        state.output.set_results(vec![BigInt::from(1), BigInt::from(2)]);
         state.output.list_state.select(Some(0));
 
         state.scroll_results(1);
@@ -360,16 +882,421 @@ mod tests {
             .unwrap();
         state.update_progress_bar();
         assert_eq!(state.output.progress, Some(50));
-        assert!(state.output.results.is_empty());
+        assert!(state.output.is_empty());
 
         let result = vec![BigInt::from(1), BigInt::from(2)];
+        let summary = FiboAggregate::from_values(&result, BigInt::from(7));
+        sender.send(FiboTaskResult::Aggregate(summary)).unwrap();
+        state.update_progress_bar();
         sender.send(FiboTaskResult::Result(result.clone())).unwrap();
         state.update_progress_bar();
-        assert_eq!(state.output.results, result);
+        let rows: Vec<OutputRow> = result.into_iter().map(OutputRow::Number).collect();
+        assert_eq!(state.output.rows, rows);
+        assert_eq!(state.output.summary.as_ref().map(|s| s.count), Some(2));
         assert_eq!(state.output.progress, None);
         assert_eq!(state.output.list_state.selected(), Some(0));
     }
 
+    #[test_case("0..10 step 3", "7" => Ok(vec![0, 3, 6, 9]); "stepped range")]
+    #[test_case("5, 10, 100..102", "7" => Ok(vec![5, 10, 100, 101]); "mixed selection")]
+    #[test_case(" ", "2" => Ok(vec![2, 3, 4]); "plain range")]
+    #[test_case("5..2", "2" => Err(Some("Range end must be > start".to_string()));
+        "invalid selection")]
+    #[test_case("", "9" => Err(Some("Range end must be > start".to_string())); "invalid range")]
+    #[tokio::test]
+    async fn test_parse_index_selection(
+        selection: &str, range_start: &str,
+    ) -> Result<Vec<usize>, Option<String>> {
+        let mut state = AppState::new();
+        state.input.selection = selection.to_string();
+        state.input.range_start = range_start.to_string();
+        state.input.range_end = "5".to_string();
+
+        match state.parse_index_selection().await {
+            Ok(selection) => Ok(selection.iter().collect()),
+            Err(()) => Err(state.error),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_calculate_polynomials() {
+        let mut state = AppState::new();
+        state.input.range_start = "3".to_string();
+        state.input.range_end = "6".to_string();
+        state.next_mode();
+        assert_eq!(state.mode, CalcMode::FibonacciPolynomials);
+
+        state.filters.filters.push(Filter {
+            filter_type: FilterType::Ge,
+            value: BigInt::from(3),
+        });
+
+        state.calculate().await;
+        run_to_end(&mut state).await;
+        assert_eq!(
+            state.output.rows,
+            [
+                OutputRow::Text(String::from("x^3 + 2x")),
+                OutputRow::Text(String::from("x^4 + 3x^2 + 1")),
+            ]
+        );
+        assert_eq!(state.output.progress, None);
+    }
+
+    #[tokio::test]
+    async fn test_calculate_words() {
+        let mut state = AppState::new();
+        state.input.range_start = "0".to_string();
+        state.input.range_end = "3".to_string();
+        state.mode = CalcMode::LucasPolynomials;
+        state.next_mode();
+        assert_eq!(state.mode, CalcMode::FibonacciWords);
+
+        state.calculate().await;
+        let words = ["[len 1] a", "[len 2] ab", "[len 3] aba"];
+        let rows: Vec<OutputRow> = words.map(|word| OutputRow::Text(word.to_string())).to_vec();
+        assert_eq!(state.output.rows, rows);
+    }
+
+    #[test_case("-4, 7 3 10" => Some(("-4".to_string(), "7".to_string())); "fibonacci like")]
+    #[test_case("1, 2, 4, 8" => None; "geometric")]
+    #[test_case("3, 7" => None; "too few to prefill")]
+    fn test_identify_terms(terms: &str) -> Option<(String, String)> {
+        let mut state = AppState::new();
+        state.input.terms = terms.to_string();
+        state.input.start1 = String::new();
+        state.input.start2 = String::new();
+
+        state.identify_terms();
+        assert!(state.recurrence.is_some());
+        assert!(state.error.is_none());
+        (!state.input.start1.is_empty()).then_some((state.input.start1, state.input.start2))
+    }
+
+    #[test_case("5" => Some("Paste at least two terms".to_string()); "too few")]
+    #[test_case("1, x, 3" => Some("Invalid term 'x'".to_string()); "invalid")]
+    #[test_case("2, 1, 3" => Some("An order 2 fit needs at least 4 terms, got 3".to_string());
+        "underdetermined")]
+    fn test_identify_terms_errors(terms: &str) -> Option<String> {
+        let mut state = AppState::new();
+        state.input.terms = terms.to_string();
+
+        state.identify_terms();
+        assert!(state.recurrence.is_none());
+        state.error
+    }
+
+    #[tokio::test]
+    async fn test_calculate_with_transform() {
+        let mut state = AppState::new();
+        state.input.range_start = "0".to_string();
+        state.input.range_end = "8".to_string();
+        state.next_transform();
+        assert_eq!(state.transform, TransformChoice::PartialSums);
+
+        state.calculate().await;
+        let mut receiver = state.output.receiver.take().expect("No receiver");
+        while let Some(msg) = receiver.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                let expected: Vec<BigInt> = [0, 1, 2, 4, 7, 12, 20, 33].map(BigInt::from).to_vec();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[test]
+    fn test_transform_cycle() {
+        let mut choice = TransformChoice::default();
+        let mut names = vec![];
+        for _ in 0..8 {
+            choice = choice.next();
+            names.push(choice.to_string());
+        }
+        assert_eq!(choice, TransformChoice::None);
+        assert_eq!(names[5], "mod 1000");
+    }
+
+    #[test_case(SetOperation::Intersection => vec![1, 2, 3]; "intersection")]
+    #[test_case(SetOperation::Difference => vec![0, 5, 8, 13]; "difference")]
+    #[tokio::test]
+    async fn test_calculate_comparison(operation: SetOperation) -> Vec<i64> {
+        let mut state = AppState::new();
+        state.mode = CalcMode::FibonacciWords;
+        state.next_mode();
+        assert_eq!(state.mode, CalcMode::Compare);
+        state.input.value_bound = "20".to_string();
+        while state.set_operation != operation {
+            state.next_set_operation();
+        }
+
+        state.calculate().await;
+        assert!(state.error.is_none());
+        state
+            .output
+            .rows
+            .iter()
+            .map(|row| match row {
+                OutputRow::Number(n) => i64::try_from(n).unwrap(),
+                OutputRow::Text(_) => panic!("Comparisons give numbers"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_calculate_comparison_invalid_seeds() {
+        let mut state = AppState::new();
+        state.mode = CalcMode::Compare;
+        state.input.compare_seeds = "2".to_string();
+
+        state.calculate().await;
+        assert_eq!(
+            state.error,
+            Some(String::from("Compare seeds must look like 'a, b'"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_closed_form() {
+        let mut state = AppState::new();
+        let closed_form = state.closed_form.clone().expect("No initial closed form");
+        assert_eq!(closed_form.linear_combination(), "G(n) = F(n)");
+
+        state.input.start1 = "2".to_string();
+        state.update_closed_form().await;
+        let closed_form = state.closed_form.clone().expect("No closed form");
+        assert_eq!(closed_form.linear_combination(), "G(n) = 2·F(n-1) + F(n)");
+
+        state.input.start2 = "-3".to_string();
+        state.update_closed_form().await;
+        let closed_form = state.closed_form.clone().expect("No closed form");
+        assert_eq!(closed_form.linear_combination(), "G(n) = 2·F(n-1) - 3·F(n)");
+
+        state.input.start2 = "1 +".to_string();
+        state.update_closed_form().await;
+        assert_eq!(state.closed_form, Some(closed_form));
+        assert!(state.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_query_huge_index() {
+        let mut state = AppState::new();
+        state.input.huge_index = "100".to_string();
+
+        state.query_huge_index().await;
+        let summary = state.huge_index.expect("No huge index summary");
+        assert_eq!(summary.digit_count, BigInt::from(21));
+        assert_eq!(summary.leading, "3542248481");
+        assert_eq!(summary.trailing, "9261915075");
+    }
+
+    #[tokio::test]
+    async fn test_query_negative_huge_index() {
+        let mut state = AppState::new();
+        state.input.huge_index = "-100".to_string();
+
+        state.query_huge_index().await;
+        assert!(state.huge_index.is_none());
+        assert_eq!(state.error, Some(String::from("Index must be >= 0, got -100")));
+    }
+
+    #[tokio::test]
+    async fn test_calculate_by_value() {
+        let mut state = AppState::new();
+        state.toggle_by_value();
+        state.input.start1 = "5".to_string();
+        state.input.start2 = "-3".to_string();
+        state.input.range_start = "-1".to_string();
+        state.input.range_end = "3".to_string();
+
+        state.calculate().await;
+        let mut receiver = state.output.receiver.take().expect("No receiver");
+        while let Some(msg) = receiver.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                let expected: Vec<BigInt> = [2, -1, 1, 0, 1, 1, 2, 3].map(BigInt::from).to_vec();
+                assert_eq!(res, expected);
+                return;
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[tokio::test]
+    async fn test_calculate_by_value_invalid_range() {
+        let mut state = AppState::new();
+        state.toggle_by_value();
+        state.input.range_start = "100".to_string();
+        state.input.range_end = "10".to_string();
+
+        state.calculate().await;
+        assert_eq!(
+            state.error,
+            Some(String::from("Value range high must be >= low"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_first_matches() {
+        let mut state = AppState::new();
+        state.mode = CalcMode::Compare;
+        state.next_mode();
+        assert_eq!(state.mode, CalcMode::FindFirst);
+        state.input.find_count = "3".to_string();
+        state.input.filter_value = "1000".to_string();
+        state.add_filter().await.unwrap();
+
+        state.calculate().await;
+        let mut receiver = state.output.receiver.take().expect("No receiver");
+        while let Some(msg) = receiver.recv().await {
+            match msg {
+                FiboTaskResult::Search(progress) => state.output.search = Some(progress),
+                FiboTaskResult::Result(res) => {
+                    let expected: Vec<BigInt> = [1597, 2584, 4181].map(BigInt::from).to_vec();
+                    assert_eq!(res, expected);
+                    assert_eq!(
+                        state.output.search.map(|p| (p.matches, p.scanned)),
+                        Some((3, 20))
+                    );
+                    return;
+                }
+                _ => {}
+            }
+        }
+        panic!("No result received");
+    }
+
+    #[test]
+    fn test_update_progress_bar_search() {
+        let mut state = AppState::new();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        state.output.receiver = Some(receiver);
+
+        let progress = SearchProgress {
+            matches: 2,
+            scanned: 5000,
+        };
+        sender.send(FiboTaskResult::Search(progress)).unwrap();
+        state.update_progress_bar();
+        assert_eq!(state.output.search, Some(progress));
+        assert_eq!(state.output.progress, None);
+    }
+
+    #[test]
+    fn test_update_progress_bar_verification() {
+        let mut state = AppState::new();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        state.output.receiver = Some(receiver);
+
+        let verifications = Verifier::new((BigInt::from(0), BigInt::from(1)))
+            .check([(0, &BigInt::from(0)), (1, &BigInt::from(2))]);
+        sender.send(FiboTaskResult::Verification(verifications.clone())).unwrap();
+        sender.send(FiboTaskResult::Calculation(50)).unwrap();
+        state.update_progress_bar();
+        state.update_progress_bar();
+
+        let kept = state.output.verification.as_ref().unwrap();
+        assert_eq!(kept, &verifications);
+        assert!(kept[0].is_verified());
+        assert!(!kept[1].is_verified());
+    }
+
+    #[tokio::test]
+    async fn test_resume_interrupted() {
+        let dir = std::env::temp_dir().join(format!("fibo_view_resume_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("earlier.checkpoint");
+        let checkpoint = Checkpoint {
+            start_nums: (BigInt::from(2), BigInt::from(1)),
+            job: CheckpointJob::Range {
+                range: 3..8,
+                next: 3,
+                pair: (BigInt::from(1), BigInt::from(3)),
+                offset: 0,
+            },
+        };
+        checkpoint.save(&path).unwrap();
+
+        let mut state = AppState::new();
+        state.use_checkpoint_dir(dir.clone());
+        assert_eq!(
+            state.resume_offer_text(),
+            Some(String::from("indices 3..8, 0 of 5 done"))
+        );
+
+        state.resume_interrupted();
+        assert!(state.resume_offer.is_none());
+        let mut receiver = state.output.receiver.take().expect("No receiver");
+        while let Some(msg) = receiver.recv().await {
+            if let FiboTaskResult::Result(res) = msg {
+                assert_eq!(res, [4, 7, 11, 18, 29].map(BigInt::from));
+                break;
+            }
+        }
+        assert!(Checkpoint::load(&path).is_err());
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn test_discard_interrupted() {
+        let dir = std::env::temp_dir().join(format!("fibo_view_discard_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("earlier.checkpoint");
+        let checkpoint = Checkpoint {
+            start_nums: (BigInt::from(0), BigInt::from(1)),
+            job: CheckpointJob::One {
+                n: BigInt::from(1_000_000),
+                bits_left: 12,
+                pair: (BigInt::from(0), BigInt::from(1)),
+            },
+        };
+        checkpoint.save(&path).unwrap();
+
+        let mut state = AppState::new();
+        state.use_checkpoint_dir(dir.clone());
+        assert_eq!(state.resume_offer_text(), Some(String::from("term 1000000")));
+
+        state.discard_interrupted();
+        assert!(state.resume_offer.is_none());
+        assert!(Checkpoint::load(&path).is_err());
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_back_to_back_runs_checkpoint_separately() {
+        let dir = std::env::temp_dir().join(format!("fibo_view_overlap_{}", std::process::id()));
+        let mut state = AppState::new();
+        state.use_checkpoint_dir(dir.clone());
+        assert!(state.resume_offer.is_none());
+        state.input.start1 = "0".to_string();
+        state.input.start2 = "1".to_string();
+
+        let mut receivers = Vec::new();
+        for end in ["5", "8"] {
+            state.input.range_start = "0".to_string();
+            state.input.range_end = end.to_string();
+            state.calculate().await;
+            receivers.push(state.output.receiver.take().expect("No receiver"));
+        }
+        assert_eq!(state.checkpoint_runs, 2);
+        assert_ne!(state.next_checkpoint_path(), state.next_checkpoint_path());
+
+        let mut results = Vec::new();
+        for mut receiver in receivers {
+            while let Some(msg) = receiver.recv().await {
+                if let FiboTaskResult::Result(res) = msg {
+                    results.push(res);
+                    break;
+                }
+            }
+        }
+        assert_eq!(results, [
+            [0, 1, 1, 2, 3].map(BigInt::from).to_vec(),
+            [0, 1, 1, 2, 3, 5, 8, 13].map(BigInt::from).to_vec(),
+        ]);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
     #[tokio::test]
     async fn test_calculate_invalid_range() {
         let mut state = AppState::new();
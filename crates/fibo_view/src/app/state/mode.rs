@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub enum CalcMode {
+    #[default]
+    Numbers,
+    FibonacciPolynomials,
+    LucasPolynomials,
+    FibonacciWords,
+    Compare,
+    FindFirst,
+}
+
+impl CalcMode {
+    pub fn next(self) -> Self {
+        match self {
+            CalcMode::Numbers => CalcMode::FibonacciPolynomials,
+            CalcMode::FibonacciPolynomials => CalcMode::LucasPolynomials,
+            CalcMode::LucasPolynomials => CalcMode::FibonacciWords,
+            CalcMode::FibonacciWords => CalcMode::Compare,
+            CalcMode::Compare => CalcMode::FindFirst,
+            CalcMode::FindFirst => CalcMode::Numbers,
+        }
+    }
+}
+
+impl Display for CalcMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            CalcMode::Numbers => "Numbers",
+            CalcMode::FibonacciPolynomials => "Fibonacci polynomials",
+            CalcMode::LucasPolynomials => "Lucas polynomials",
+            CalcMode::FibonacciWords => "Fibonacci words",
+            CalcMode::Compare => "Compare sequences",
+            CalcMode::FindFirst => "Find first N",
+        })
+    }
+}
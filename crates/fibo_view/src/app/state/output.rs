@@ -1,13 +1,146 @@
-use fibo_calc::FiboTaskReceiver;
+use crate::app::state::DisplayFormat;
+use fibo_calc::{FiboAggregate, FiboTaskReceiver, SearchProgress, Verification, to_decimal_string};
 use num_bigint::BigInt;
+use num_rational::BigRational;
 use ratatui::widgets::ListState;
+use std::collections::HashMap;
+use std::ops::Range;
+
+const RATIO_DIGITS: usize = 10;
+const ROW_WIDTH: usize = 50;
+
+/// One line of the result list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputRow {
+    Number(BigInt),
+    /// Polynomials and words, shown as they are
+    Text(String),
+}
 
 #[derive(Default)]
 pub struct OutputState {
-    pub results: Vec<BigInt>,
+    /// Set through `set_results` and `set_texts`, which reset the rendered rows
+    pub rows: Vec<OutputRow>,
+    pub summary: Option<FiboAggregate>,
+    pub show_ratio: bool,
+    pub format: DisplayFormat,
     pub progress: Option<u8>,
+    /// Last report of a find-first search, kept after it finishes
+    pub search: Option<SearchProgress>,
+    /// Verdicts of the last verified run, kept through later progress messages
+    pub verification: Option<Vec<Verification>>,
     pub list_state: ListState,
     pub viewport_start: usize,
     pub viewport_size: usize,
     pub receiver: Option<FiboTaskReceiver>,
+    /// Row texts already rendered, so a draw never stringifies a value twice
+    rendered: HashMap<usize, String>,
+}
+
+impl OutputState {
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.rows.clear();
+        self.summary = None;
+        self.rendered.clear();
+    }
+
+    pub fn set_results(&mut self, results: Vec<BigInt>) {
+        self.set_rows(results.into_iter().map(OutputRow::Number).collect());
+    }
+
+    pub fn set_texts(&mut self, texts: Vec<String>) {
+        self.set_rows(texts.into_iter().map(OutputRow::Text).collect());
+    }
+
+    fn set_rows(&mut self, rows: Vec<OutputRow>) {
+        self.rows = rows;
+        self.rendered.clear();
+    }
+
+    pub fn toggle_ratio(&mut self) {
+        self.show_ratio = !self.show_ratio;
+        self.rendered.clear();
+    }
+
+    pub fn next_format(&mut self) {
+        self.format = self.format.next();
+        self.rendered.clear();
+    }
+
+    /// `num` in the current display format, at most `width` characters.
+    pub fn format_value(&self, num: &BigInt, width: usize) -> String {
+        self.format.number_format(width).format(num)
+    }
+
+    /// Rows in the viewport, or all of them before the first layout.
+    pub fn visible_rows(&self) -> Range<usize> {
+        if self.viewport_size == 0 {
+            return 0..self.len();
+        }
+        let end = (self.viewport_start + self.viewport_size).min(self.len());
+        self.viewport_start.min(end)..end
+    }
+
+    /// Renders the visible rows that are not cached yet.
+    pub fn prepare_visible_rows(&mut self) {
+        for index in self.visible_rows() {
+            if !self.rendered.contains_key(&index) {
+                let text = self.format_row(index);
+                self.rendered.insert(index, text);
+            }
+        }
+    }
+
+    pub fn row_text(&self, index: usize) -> String {
+        match self.rendered.get(&index) {
+            Some(text) => text.clone(),
+            None => self.format_row(index),
+        }
+    }
+
+    fn format_row(&self, index: usize) -> String {
+        match &self.rows[index] {
+            OutputRow::Number(num) => {
+                let formatted = self.format_value(num, ROW_WIDTH);
+                match self.format_ratio(index, num) {
+                    Some(ratio) => format!("{}  φ≈{}", formatted, ratio),
+                    None => formatted,
+                }
+            }
+            OutputRow::Text(text) => truncate(text.clone(), ROW_WIDTH),
+        }
+    }
+
+    fn format_ratio(&self, index: usize, num: &BigInt) -> Option<String> {
+        if !self.show_ratio {
+            return None;
+        }
+
+        let previous = index.checked_sub(1).map(|i| &self.rows[i]);
+        let Some(OutputRow::Number(previous)) = previous else {
+            return None;
+        };
+        if previous == &BigInt::ZERO {
+            return Some(String::from("-"));
+        }
+
+        let ratio = BigRational::new(num.clone(), previous.clone());
+        Some(to_decimal_string(&ratio, RATIO_DIGITS))
+    }
+}
+
+fn truncate(text: String, width: usize) -> String {
+    if text.chars().count() > width {
+        format!("{}...", text.chars().take(width - 3).collect::<String>())
+    } else {
+        text
+    }
 }
@@ -0,0 +1,56 @@
+use fibo_calc::Transform;
+use num_bigint::BigInt;
+use std::fmt::Display;
+
+/// Modulus of the `mod` choice, keeping the last three digits.
+const TRANSFORM_MODULUS: u32 = 1000;
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub enum TransformChoice {
+    #[default]
+    None,
+    PartialSums,
+    Differences,
+    Squares,
+    ConsecutiveProducts,
+    Cassini,
+    Modulo,
+    DigitSum,
+}
+
+impl TransformChoice {
+    pub fn next(self) -> Self {
+        match self {
+            TransformChoice::None => TransformChoice::PartialSums,
+            TransformChoice::PartialSums => TransformChoice::Differences,
+            TransformChoice::Differences => TransformChoice::Squares,
+            TransformChoice::Squares => TransformChoice::ConsecutiveProducts,
+            TransformChoice::ConsecutiveProducts => TransformChoice::Cassini,
+            TransformChoice::Cassini => TransformChoice::Modulo,
+            TransformChoice::Modulo => TransformChoice::DigitSum,
+            TransformChoice::DigitSum => TransformChoice::None,
+        }
+    }
+
+    pub fn to_transform(self) -> Option<Transform> {
+        match self {
+            TransformChoice::None => None,
+            TransformChoice::PartialSums => Some(Transform::PartialSums),
+            TransformChoice::Differences => Some(Transform::Differences),
+            TransformChoice::Squares => Some(Transform::Squares),
+            TransformChoice::ConsecutiveProducts => Some(Transform::ConsecutiveProducts),
+            TransformChoice::Cassini => Some(Transform::Cassini),
+            TransformChoice::Modulo => Transform::modulo(BigInt::from(TRANSFORM_MODULUS)).ok(),
+            TransformChoice::DigitSum => Some(Transform::DigitSum),
+        }
+    }
+}
+
+impl Display for TransformChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_transform() {
+            Some(transform) => write!(f, "{}", transform),
+            None => write!(f, "none"),
+        }
+    }
+}
@@ -1,16 +1,102 @@
 use crate::app::state::{Filter, FilterType};
-use fibo_calc::{FiboBuilder, FiboCalc, FiboTaskReceiver};
+use fibo_calc::{
+    AggregateMode, CheckpointConfig, ClosedForm, DigitSummary, FiboBuilder, FiboCalc,
+    FiboTaskReceiver, FirstMatches, IndexSelection, LinearRecurrence, PolynomialKind,
+    SetOperation, Transform, TransformStage, ValueRange,
+};
 use num_bigint::BigInt;
+use std::path::{Path, PathBuf};
 
 pub async fn calculate_fibonacci(
-    start_nums: (BigInt, BigInt), range: std::ops::Range<usize>, filters: &[Filter],
+    start_nums: (BigInt, BigInt), selection: IndexSelection, value_range: Option<ValueRange>,
+    filters: &[Filter], transform: Option<Transform>, verify: bool, checkpoint: Option<PathBuf>,
 ) -> FiboTaskReceiver {
     let mut builder = FiboBuilder::default();
 
-    builder
-        .set_start_nums(Some(start_nums))
-        .set_range_by_id(Some(range.start..range.end));
+    builder.set_start_nums(Some(start_nums));
+    builder.set_verify(verify);
+    builder.set_checkpoint(checkpoint.map(CheckpointConfig::new));
+    match (value_range, selection.as_range()) {
+        (Some(value_range), _) => builder.set_value_range(Some(value_range)),
+        (None, Some(range)) => builder.set_range_by_id(Some(range)),
+        (None, None) => builder.set_index_selection(Some(selection)),
+    };
 
+    add_filters(&mut builder, filters);
+    add_summary(&mut builder);
+
+    if let Some(transform) = transform {
+        builder.add_transform(TransformStage::AfterFilters, transform);
+    }
+
+    let calc = FiboCalc::new(builder);
+
+
+    calc.calc_background()
+}
+
+/// Continues the run checkpointed at `path` with the current filters and
+/// transform, which the checkpoint does not keep.
+pub fn resume_calculation(
+    path: &Path, filters: &[Filter], transform: Option<Transform>, verify: bool,
+) -> Result<FiboTaskReceiver, String> {
+    let mut builder = FiboBuilder::default();
+
+    builder.set_verify(verify);
+    add_filters(&mut builder, filters);
+    add_summary(&mut builder);
+
+    if let Some(transform) = transform {
+        builder.add_transform(TransformStage::AfterFilters, transform);
+    }
+
+    FiboCalc::new(builder).resume(path)
+}
+
+/// Filters decide what counts as a match, the transform runs on the matches.
+pub async fn find_first_matches(
+    start_nums: (BigInt, BigInt), search: FirstMatches, filters: &[Filter],
+    transform: Option<Transform>,
+) -> FiboTaskReceiver {
+    let mut builder = FiboBuilder::default();
+
+    builder.set_start_nums(Some(start_nums));
+    builder.set_search(Some(search));
+    add_filters(&mut builder, filters);
+    add_summary(&mut builder);
+
+    if let Some(transform) = transform {
+        builder.add_transform(TransformStage::AfterFilters, transform);
+    }
+
+    FiboCalc::new(builder).calc_background()
+}
+
+/// Filters apply to both sequences before they are combined.
+pub fn compare_sequences(
+    start_nums: (BigInt, BigInt), other_start_nums: (BigInt, BigInt), filters: &[Filter],
+    operation: SetOperation, bound: &BigInt,
+) -> Vec<BigInt> {
+    let mut builder = FiboBuilder::default();
+    builder.set_start_nums(Some(start_nums));
+    add_filters(&mut builder, filters);
+
+    let mut other = FiboBuilder::default();
+    other.set_start_nums(Some(other_start_nums));
+    add_filters(&mut other, filters);
+
+    FiboCalc::new(builder).calc_set_operation(&other, operation, bound)
+}
+
+/// The task sends the totals of the summary line along with the terms.
+fn add_summary(builder: &mut FiboBuilder) {
+    builder.set_aggregate(Some(AggregateMode {
+        keep_values: true,
+        ..Default::default()
+    }));
+}
+
+fn add_filters(builder: &mut FiboBuilder, filters: &[Filter]) {
     for filter in filters {
         let value = filter.value.clone();
         match filter.filter_type {
@@ -18,9 +104,47 @@ pub async fn calculate_fibonacci(
             FilterType::Le => builder.add_filter(move |num| num <= &value),
         };
     }
+}
 
-    let calc = FiboCalc::new(builder);
+pub fn query_huge_index(
+    start_nums: (BigInt, BigInt), index: BigInt, k: usize,
+) -> Result<DigitSummary, String> {
+    let mut builder = FiboBuilder::default();
+    builder.set_start_nums(Some(start_nums));
 
+    FiboCalc::new(builder).calc_digits(index, k)
+}
 
-    calc.calc_background()
+/// Filters see the value of each polynomial at x = 1.
+pub fn calculate_polynomials(
+    kind: PolynomialKind, selection: IndexSelection, filters: &[Filter],
+) -> FiboTaskReceiver {
+    let mut builder = FiboBuilder::default();
+    builder.set_index_selection(Some(selection));
+    add_filters(&mut builder, filters);
+
+    FiboCalc::new(builder).calc_polynomials(kind)
+}
+
+/// Words are shown as a length tag followed by a prefix of the word.
+pub fn calculate_words(selection: IndexSelection, prefix_len: usize) -> Vec<String> {
+    let mut builder = FiboBuilder::default();
+    builder.set_index_selection(Some(selection));
+
+    FiboCalc::new(builder)
+        .calc_words(('a', 'b'))
+        .iter()
+        .map(|word| format!("[len {}] {}", word.len(), word.prefix(prefix_len)))
+        .collect()
+}
+
+pub fn identify_recurrence(terms: &[BigInt]) -> LinearRecurrence {
+    LinearRecurrence::identify(terms)
+}
+
+pub fn closed_form(start_nums: &(BigInt, BigInt)) -> ClosedForm {
+    let mut builder = FiboBuilder::default();
+    builder.set_start_nums(Some(start_nums.clone()));
+
+    FiboCalc::new(builder).calc_closed_form()
 }
@@ -2,4 +2,7 @@ mod expr_calc;
 mod fibo_calc;
 
 pub use expr_calc::calculate_expr;
-pub use fibo_calc::calculate_fibonacci;
+pub use fibo_calc::{
+    calculate_fibonacci, calculate_polynomials, calculate_words, closed_form, compare_sequences,
+    find_first_matches, identify_recurrence, query_huge_index, resume_calculation,
+};
@@ -4,9 +4,18 @@ use ratatui::style::Style;
 #[derive(Clone)]
 pub struct ListStyles {
     pub active_input: Style,
+    pub mode: Style,
+    pub resume_offer: Style,
     pub inactive_start: Style,
+    pub closed_form: Style,
     pub inactive_range: Style,
     pub inactive_filter: Style,
+    pub transform: Style,
+    pub inactive_huge_index: Style,
+    pub huge_index_result: Style,
+    pub inactive_terms: Style,
+    pub inactive_compare: Style,
+    pub recurrence_result: Style,
     pub filter_header: Style,
     pub no_filter: Style,
     pub filter_item: Style,
@@ -22,9 +31,18 @@ impl Default for ListStyles {
     fn default() -> Self {
         Self {
             active_input: Style::new().bold().yellow(),
+            mode: Style::new().bold().light_yellow(),
+            resume_offer: Style::new().bold().light_magenta(),
             inactive_start: Style::new().white(),
+            closed_form: Style::new().italic().gray(),
             inactive_range: Style::new().light_blue(),
             inactive_filter: Style::new().light_green(),
+            transform: Style::new().green(),
+            inactive_huge_index: Style::new().light_cyan(),
+            huge_index_result: Style::new().italic().light_cyan(),
+            inactive_terms: Style::new().light_red(),
+            inactive_compare: Style::new().light_yellow(),
+            recurrence_result: Style::new().italic().light_red(),
             filter_header: Style::new().bold().magenta(),
             no_filter: Style::new().italic().dark_gray(),
             filter_item: Style::new().light_magenta(),
@@ -5,6 +5,6 @@ use ratatui::widgets::Paragraph;
 mod list_styles;
 mod result_renderer;
 
-pub fn render(state: &AppState) -> Paragraph {
+pub fn render(state: &AppState) -> Paragraph<'_> {
     ResultRenderer::new(state).render()
 }
@@ -1,4 +1,4 @@
-use crate::app::state::{AppState, InputMode};
+use crate::app::state::{AppState, CalcMode, InputMode};
 use ratatui::{
     prelude::*,
     text::{Line, Text},
@@ -23,7 +23,10 @@ impl<'a> ResultRenderer<'a> {
     pub fn render(self) -> Paragraph<'a> {
         let mut lines = Vec::new();
 
+        self.append_resume_offer(&mut lines);
         self.append_input_fields(&mut lines);
+        self.append_huge_index_section(&mut lines);
+        self.append_terms_section(&mut lines);
         self.append_filter_section(&mut lines);
         self.append_action_section(&mut lines);
         self.append_navigation_section(&mut lines);
@@ -40,16 +43,38 @@ impl<'a> ResultRenderer<'a> {
         } else {
             match target_mode {
                 InputMode::Start1 | InputMode::Start2 => self.styles.inactive_start,
-                InputMode::RangeStart | InputMode::RangeEnd => self.styles.inactive_range,
+                InputMode::RangeStart | InputMode::RangeEnd | InputMode::Selection => {
+                    self.styles.inactive_range
+                }
                 InputMode::FilterValue => self.styles.inactive_filter,
+                InputMode::HugeIndex => self.styles.inactive_huge_index,
+                InputMode::Terms => self.styles.inactive_terms,
+                InputMode::CompareSeeds | InputMode::ValueBound => self.styles.inactive_compare,
+                InputMode::FindCount => self.styles.inactive_compare,
                 _ => Style::default(),
             }
         }
     }
 
+    fn append_resume_offer(&self, lines: &mut Vec<Line>) {
+        if let Some(job) = self.state.resume_offer_text() {
+            lines.extend([
+                Line::from(""),
+                Line::from(format!("⏯ Interrupted run: {}", job)).style(self.styles.resume_offer),
+                Line::from("   [n] Resume        [j] Discard").style(self.styles.action_item),
+            ]);
+        }
+    }
+
     fn append_input_fields(&self, lines: &mut Vec<Line>) {
         lines.extend([
             Line::from(""),
+            Line::from(format!("🧮 Mode [m]: {}", self.state.mode)).style(self.styles.mode),
+        ]);
+        self.append_compare_fields(lines);
+        self.append_find_fields(lines);
+
+        lines.extend([
             Line::from(format!(
                 "🔢 Start Number 1 [1]: {}",
                 self.state.input.start1
@@ -60,20 +85,140 @@ impl<'a> ResultRenderer<'a> {
                 self.state.input.start2
             ))
             .style(self.get_field_style(&self.state.input_mode, InputMode::Start2)),
+        ]);
+
+        if let Some(closed_form) = &self.state.closed_form {
+            lines.extend(
+                closed_form
+                    .to_string()
+                    .lines()
+                    .map(|line| Line::from(format!("   {}", line)).style(self.styles.closed_form)),
+            );
+        }
+
+        let (start_label, end_label, select_by) = if self.state.by_value {
+            ("Value Low", "Value High", "value")
+        } else {
+            ("Range Start", "Range End", "index")
+        };
+
+        lines.extend([
             Line::from(""),
+            Line::from(format!("🎯 Select By [y]: {}", select_by)).style(self.styles.mode),
             Line::from(format!(
-                "📍 Range Start [s]: {}",
-                self.state.input.range_start
+                "📍 {} [s]: {}",
+                start_label, self.state.input.range_start
             ))
             .style(self.get_field_style(&self.state.input_mode, InputMode::RangeStart)),
-            Line::from(format!("📍 Range End [e]: {}", self.state.input.range_end))
-                .style(self.get_field_style(&self.state.input_mode, InputMode::RangeEnd)),
+            Line::from(format!(
+                "📍 {} [e]: {}",
+                end_label, self.state.input.range_end
+            ))
+            .style(self.get_field_style(&self.state.input_mode, InputMode::RangeEnd)),
+        ]);
+
+        if !self.state.by_value {
+            lines.push(
+                Line::from(format!("🧩 Selection [S]: {}", self.state.input.selection))
+                    .style(self.get_field_style(&self.state.input_mode, InputMode::Selection)),
+            );
+        }
+
+        lines.extend([
             Line::from(""),
             Line::from(format!(
                 "🔍 Filter Value [v]: {}{}",
                 self.state.filters.filter_type, self.state.input.filter_value
             ))
             .style(self.get_field_style(&self.state.input_mode, InputMode::FilterValue)),
+            Line::from(format!("🔁 Transform [x]: {}", self.state.transform))
+                .style(self.styles.transform),
+            Line::from(format!(
+                "✔️ Verify [w]: {}",
+                if self.state.verify { "on" } else { "off" }
+            ))
+            .style(self.styles.mode),
+        ]);
+    }
+
+    fn append_compare_fields(&self, lines: &mut Vec<Line>) {
+        if self.state.mode != CalcMode::Compare {
+            return;
+        }
+
+        lines.extend([
+            Line::from(format!(
+                "⚖️ Compare Seeds [k]: {}",
+                self.state.input.compare_seeds
+            ))
+            .style(self.get_field_style(&self.state.input_mode, InputMode::CompareSeeds)),
+            Line::from(format!(
+                "📏 Value Bound [b]: {}",
+                self.state.input.value_bound
+            ))
+            .style(self.get_field_style(&self.state.input_mode, InputMode::ValueBound)),
+            Line::from(format!("   Operation [o]: {}", self.state.set_operation))
+                .style(self.styles.mode),
+        ]);
+    }
+
+    fn append_find_fields(&self, lines: &mut Vec<Line>) {
+        if self.state.mode != CalcMode::FindFirst {
+            return;
+        }
+
+        lines.extend([
+            Line::from(format!("🔎 Find First [f]: {}", self.state.input.find_count))
+                .style(self.get_field_style(&self.state.input_mode, InputMode::FindCount)),
+            Line::from("   Filtered terms from Range Start on, no end").style(self.styles.mode),
+        ]);
+    }
+
+    fn append_huge_index_section(&self, lines: &mut Vec<Line>) {
+        lines.extend([
+            Line::from(""),
+            Line::from(format!(
+                "🌌 Huge Index [h]: {}",
+                self.state.input.huge_index
+            ))
+            .style(self.get_field_style(&self.state.input_mode, InputMode::HugeIndex)),
+        ]);
+
+        if let Some(summary) = &self.state.huge_index {
+            lines.push(
+                Line::from(format!(
+                    "   {}{}…{} ({} digits)",
+                    if summary.negative { "-" } else { "" },
+                    summary.leading,
+                    summary.trailing,
+                    summary.digit_count
+                ))
+                .style(self.styles.huge_index_result),
+            );
+        }
+    }
+
+    fn append_terms_section(&self, lines: &mut Vec<Line>) {
+        lines.extend([
+            Line::from(""),
+            Line::from(format!("🧬 Sample Terms [t]: {}", self.state.input.terms))
+                .style(self.get_field_style(&self.state.input_mode, InputMode::Terms)),
+        ]);
+
+        if let Some(recurrence) = &self.state.recurrence {
+            lines.push(
+                Line::from(format!("   {} (order {})", recurrence, recurrence.order()))
+                    .style(self.styles.recurrence_result),
+            );
+            if let Some((a, b)) = recurrence.fibonacci_start() {
+                lines.push(
+                    Line::from(format!("   Fibonacci-like, start numbers {}, {}", a, b))
+                        .style(self.styles.recurrence_result),
+                );
+            }
+        }
+
+        lines.extend([
             Line::from(""),
             Line::from("🔍 Active Filters:").style(self.styles.filter_header),
         ]);
@@ -104,6 +249,10 @@ impl<'a> ResultRenderer<'a> {
             Line::from("   [a] Add filter    [d] Delete filter").style(self.styles.action_item),
             Line::from("   [g] Filter ≥      [l] Filter ≤").style(self.styles.action_item),
             Line::from("   [r] Calculate     [c] Clear filters").style(self.styles.action_item),
+            Line::from("   [p] Ratio column  [u] Huge index").style(self.styles.action_item),
+            Line::from("   [i] Identify terms").style(self.styles.action_item),
+            Line::from("   [y] Select by index or value").style(self.styles.action_item),
+            Line::from("   [w] Verify results").style(self.styles.action_item),
         ]);
     }
 
@@ -111,7 +260,8 @@ impl<'a> ResultRenderer<'a> {
         lines.extend([
             Line::from(""),
             Line::from("🎮 Navigation:").style(self.styles.nav_header),
-            Line::from("   1,2,s,e,v Edit fields").style(self.styles.nav_item),
+            Line::from("   1,2,s,e,S,v,h,t,k,b,f Edit fields").style(self.styles.nav_item),
+            Line::from("   [S] takes 5, 10, 100..110 step 2").style(self.styles.nav_item),
             Line::from("   ESC/Enter Exit edit mode").style(self.styles.nav_item),
             Line::from("   ↑↓ Navigate results").style(self.styles.nav_item),
         ]);
@@ -3,6 +3,7 @@ mod list_styles;
 mod output_panel;
 
 use crate::app::state::AppState;
+use fibo_calc::SearchProgress;
 use list_styles::ListStyles;
 use ratatui::{
     Frame,
@@ -78,9 +79,9 @@ fn render_status_section(
     let status_block = Block::bordered().title_style(style).border_style(style);
     frame.render_widget(&status_block, areas.status);
 
-    let position_info = if !state.output.results.is_empty() {
+    let position_info = if !state.output.is_empty() {
         let selected = state.output.list_state.selected().unwrap_or(0);
-        let total = state.output.results.len();
+        let total = state.output.len();
         format!("{}/{}", selected + 1, total)
     } else {
         String::from("0/0")
@@ -107,8 +108,9 @@ fn render_main_sections(
         .title_style(styles.input_block)
         .border_style(styles.input_block);
 
+    let output_title = format!(" 📊 Fibonacci Results · {} [z] ", state.output.format);
     let output_block = Block::bordered()
-        .title(" 📊 Fibonacci Results ")
+        .title(output_title)
         .title_style(styles.output_block)
         .border_style(styles.output_block);
 
@@ -129,6 +131,10 @@ fn render_progress_section(
         render_progress_bar_with_progress(frame, areas, styles, progress);
         return;
     }
+    if let Some(search) = state.output.search {
+        render_search_progress(frame, areas, styles, search);
+        return;
+    }
     let empty_block = Block::bordered()
         .title(" 💤 Ready ")
         .title_style(Style::new().dim())
@@ -143,6 +149,25 @@ fn render_progress_section(
     frame.render_widget(Paragraph::new(ready_text).centered(), empty_inner);
 }
 
+fn render_search_progress(
+    frame: &mut Frame, areas: &LayoutAreas, styles: &ListStyles, search: SearchProgress,
+) {
+    let search_block = Block::bordered()
+        .title(" 🔎 Search Progress ")
+        .title_style(styles.progress_bar)
+        .border_style(styles.progress_bar);
+
+    let search_inner = search_block.inner(areas.progress);
+    frame.render_widget(search_block, areas.progress);
+
+    let text = Line::from(format!(
+        "{} matches found, {} indices scanned",
+        search.matches, search.scanned
+    ))
+    .style(styles.progress_bar);
+    frame.render_widget(Paragraph::new(text).centered(), search_inner);
+}
+
 fn render_progress_bar_with_progress(
     frame: &mut Frame, areas: &LayoutAreas, styles: &ListStyles, progress: u8,
 ) {
@@ -13,6 +13,8 @@ pub struct ListStyles {
     pub calculating_text: Style,
     pub progress_text: Style,
     pub note_list_item: Style,
+    pub summary: Style,
+    pub verification_failed: Style,
 }
 
 impl Default for ListStyles {
@@ -28,6 +30,8 @@ impl Default for ListStyles {
             calculating_text: Style::new().bold().green(),
             progress_text: Style::new().italic().light_blue(),
             note_list_item: Style::new().italic().dark_gray(),
+            summary: Style::new().bold().light_green(),
+            verification_failed: Style::new().bold().light_red(),
         }
     }
 }
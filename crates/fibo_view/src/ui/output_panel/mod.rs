@@ -7,9 +7,11 @@ mod list_styles;
 mod result_renderer;
 
 
-pub fn render(state: &mut AppState, area: Rect) -> List {
-    let viewport_height = area.height.saturating_sub(2) as usize;
+pub fn render(state: &mut AppState, area: Rect) -> List<'_> {
+    // Two lines for the scroll hints and one for the summary line
+    let viewport_height = area.height.saturating_sub(3) as usize;
     state.output.viewport_size = viewport_height.max(1);
+    state.output.prepare_visible_rows();
 
     ResultRenderer::new(state).render()
 }
@@ -1,5 +1,5 @@
 use crate::app::state::AppState;
-use num_bigint::BigInt;
+use fibo_calc::Verdict;
 use ratatui::prelude::*;
 use ratatui::{
     style::Style,
@@ -8,6 +8,8 @@ use ratatui::{
 
 use crate::ui::output_panel::list_styles::ListStyles;
 
+const SUMMARY_WIDTH: usize = 15;
+
 pub struct ResultRenderer<'a> {
     pub state: &'a AppState,
     pub styles: ListStyles,
@@ -24,7 +26,7 @@ impl<'a> ResultRenderer<'a> {
     pub fn render(&self) -> List<'a> {
         if self.state.output.progress.is_some() {
             self.render_calculating_state()
-        } else if self.state.output.results.is_empty() {
+        } else if self.state.output.is_empty() {
             self.render_empty_state()
         } else {
             self.render_results()
@@ -56,7 +58,13 @@ impl<'a> ResultRenderer<'a> {
     }
 
     fn render_results(&self) -> List<'a> {
-        let items = self.format_result_items();
+        let mut items = self.format_result_items();
+        if let Some(summary) = self.format_summary_item() {
+            items.push(summary);
+        }
+        if let Some(verification) = self.format_verification_item() {
+            items.push(verification);
+        }
 
         List::new(items)
             .highlight_style(self.styles.selected_item)
@@ -97,20 +105,13 @@ impl<'a> ResultRenderer<'a> {
     }
 
     fn format_result_items(&self) -> Vec<ListItem<'a>> {
+        let total_items = self.state.output.len();
+        let visible = self.state.output.visible_rows();
         if self.state.output.viewport_size == 0 {
-            return self
-                .state
-                .output
-                .results
-                .iter()
-                .enumerate()
-                .map(|(i, num)| self.format_result_item(i, num))
-                .collect();
+            return visible.map(|i| self.format_result_item(i)).collect();
         }
 
-        let total_items = self.state.output.results.len();
-        let viewport_end =
-            (self.state.output.viewport_start + self.state.output.viewport_size).min(total_items);
+        let viewport_end = visible.end;
 
         let mut items = Vec::new();
 
@@ -121,15 +122,8 @@ impl<'a> ResultRenderer<'a> {
             );
         }
 
-        let viewport_items: Vec<ListItem> = self
-            .state
-            .output
-            .results
-            .iter()
-            .enumerate()
-            .skip(self.state.output.viewport_start)
-            .take(viewport_end - self.state.output.viewport_start)
-            .map(|(i, num)| self.format_result_item(i, num))
+        let viewport_items: Vec<ListItem> = (self.state.output.viewport_start..viewport_end)
+            .map(|i| self.format_result_item(i))
             .collect();
 
         items.extend(viewport_items);
@@ -144,12 +138,45 @@ impl<'a> ResultRenderer<'a> {
         items
     }
 
-    fn format_result_item(&self, index: usize, num: &'a BigInt) -> ListItem<'a> {
-        let formatted = if num.to_string().len() > 50 {
-            format!("{}...", &num.to_string()[..47])
-        } else {
-            num.to_string()
-        };
+    fn format_summary_item(&self) -> Option<ListItem<'a>> {
+        let summary = self.state.output.summary.as_ref()?;
+        let shorten = |num| self.state.output.format_value(num, SUMMARY_WIDTH);
+        let min = summary.min.as_ref().map(shorten).unwrap_or_default();
+        let max = summary.max.as_ref().map(shorten).unwrap_or_default();
+
+        Some(
+            ListItem::new(format!(
+                "Σ {} | n {} | min {} | max {}",
+                shorten(&summary.sum),
+                summary.count,
+                min,
+                max
+            ))
+            .style(self.styles.summary),
+        )
+    }
+
+    fn format_verification_item(&self) -> Option<ListItem<'a>> {
+        let verifications = self.state.output.verification.as_ref()?;
+        let total = verifications.len();
+
+        Some(match verifications.iter().find(|v| !v.is_verified()) {
+            None => ListItem::new(format!("✅ {}/{} terms verified", total, total))
+                .style(self.styles.summary),
+            Some(failure) => {
+                let failed = verifications.iter().filter(|v| !v.is_verified()).count();
+                let details = match &failure.verdict {
+                    Verdict::Failed(details) => details.as_str(),
+                    Verdict::Verified => "",
+                };
+                ListItem::new(format!("❌ {} of {} failed: {}", failed, total, details))
+                    .style(self.styles.verification_failed)
+            }
+        })
+    }
+
+    fn format_result_item(&self, index: usize) -> ListItem<'a> {
+        let formatted = self.state.output.row_text(index);
 
         let is_selected = index == self.state.output.list_state.selected().unwrap_or(0);
 
@@ -159,7 +186,7 @@ impl<'a> ResultRenderer<'a> {
             formatted
         };
 
-        let style = if index % 2 == 0 {
+        let style = if index.is_multiple_of(2) {
             Style::new().white()
         } else {
             Style::new().light_blue()